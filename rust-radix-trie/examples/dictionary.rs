@@ -0,0 +1,128 @@
+//! Insertion benchmark on a dictionary of one million words.
+//!
+//! The words are inserted by batches of one hundred thousand. When the splits of the nodes
+//! copied the subtree of the split node, the cost of an insertion grew with the size of the trie,
+//! so the time of a batch grew linearly from one batch to the next. As the subtrees are now moved,
+//! the time of a word only depends on its length: the benchmark checks that the time per word of
+//! the last batch has not grown like the amount of stored words. The same words are then built
+//! from sorted input.
+//!
+//! ```sh
+//! cargo run --release --example dictionary
+//! cargo run --release --example dictionary -- /usr/share/dict/words
+//! ```
+
+extern crate radix_trie;
+
+use std::env;
+use std::fs;
+use std::time::Instant;
+
+use radix_trie::RadixTrie;
+
+/// Amount of generated words when no dictionary file is given.
+const WORDS_COUNT: usize = 1_000_000;
+
+/// Amount of batches the insertions are divided into.
+const BATCHES_COUNT: usize = 10;
+
+/// Maximum growth of the time per word between the first and the last batch, as a fraction of
+/// the growth of the amount of stored words: a linear cost per insertion would reach one.
+const MAXIMUM_GROWTH: f64 = 0.5;
+
+/// Generates pseudo-random lowercase words, always the same ones.
+///
+/// # Args:
+///
+/// `count` - the amount of words to generate
+///
+/// # Returns:
+///
+/// the generated words
+fn generate_words(count: usize) -> Vec<String> {
+
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+
+    let mut next = move || {
+        state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        (state >> 33) as usize
+    };
+
+    (0..count)
+        .map(|_| {
+            let length = 3 + next() % 10;
+            (0..length).map(|_| (b'a' + (next() % 26) as u8) as char).collect()
+        })
+        .collect()
+}
+
+fn main() {
+
+    let words = match env::args().nth(1) {
+        Some(path) => fs::read_to_string(path)
+            .expect("cannot read the dictionary file")
+            .lines()
+            .map(|line| line.to_string())
+            .collect(),
+        None => generate_words(WORDS_COUNT),
+    };
+
+    println!("{} words", words.len());
+
+    let mut trie = RadixTrie::new();
+    let batch_length = words.len().div_ceil(BATCHES_COUNT).max(1);
+    let mut times = Vec::new();
+    let start = Instant::now();
+
+    for (index, batch) in words.chunks(batch_length).enumerate() {
+
+        let batch_start = Instant::now();
+
+        for word in batch {
+            trie.insert(word);
+        }
+
+        let batch_time = batch_start.elapsed();
+
+        println!(
+            "batch {:>2}: {:>8} words inserted in {:>8.1?} ({} words stored)",
+            index + 1,
+            batch.len(),
+            batch_time,
+            trie.len(),
+        );
+
+        times.push((batch_time.as_secs_f64() / batch.len() as f64, trie.len()));
+    }
+
+    println!("insertion of all the words: {:.1?}", start.elapsed());
+
+    if let (Some(&(first_time, first_count)), Some(&(last_time, last_count))) = (times.first(), times.last()) {
+
+        let time_growth = last_time / first_time;
+        let count_growth = last_count as f64 / first_count as f64;
+
+        println!(
+            "time per word grew x{:.2} while the stored words grew x{:.2}",
+            time_growth,
+            count_growth,
+        );
+
+        assert!(
+            count_growth <= 1.0 || time_growth < 1.0 + MAXIMUM_GROWTH * (count_growth - 1.0),
+            "the time per word grows with the size of the trie",
+        );
+    }
+
+    let mut sorted = words.clone();
+    sorted.sort();
+    sorted.dedup();
+
+    let start = Instant::now();
+    let built = RadixTrie::from_sorted_iter(sorted.iter().map(|word| &word[..]))
+        .expect("the words are sorted");
+
+    println!("construction from the sorted words: {:.1?}", start.elapsed());
+
+    assert_eq!(built.len(), trie.len());
+}
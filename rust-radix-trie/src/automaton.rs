@@ -0,0 +1,166 @@
+use rt::RadixTrieMap;
+
+/// A finite automaton reading the keys character by character. The keys accepted by the
+/// automaton can be searched into a radix trie: the states reached by a node characters
+/// are computed once, then shared by all the keys of the node subtree, and the subtrees
+/// are skipped as soon as the reached state cannot lead to any match.
+pub trait Automaton {
+
+    /// The state of the automaton after reading some characters.
+    type State;
+
+    /// Getter of the state of the automaton before reading any character.
+    ///
+    /// # Returns:
+    ///
+    /// the start state
+    fn start(&self) -> Self::State;
+
+    /// Computes the state reached after reading the given character.
+    ///
+    /// # Arguments:
+    ///
+    /// `state` - the state before the character
+    /// `character` - the read character
+    ///
+    /// # Returns:
+    ///
+    /// the state after the character
+    fn step(&self, state: &Self::State, character: char) -> Self::State;
+
+    /// Indicates if the characters read until the given state are accepted.
+    ///
+    /// # Arguments:
+    ///
+    /// `state` - the state to check
+    ///
+    /// # Returns:
+    ///
+    /// true if the read characters are accepted
+    fn is_match(&self, state: &Self::State) -> bool;
+
+    /// Indicates if some characters can still be read from the given state to reach a match.
+    ///
+    /// # Arguments:
+    ///
+    /// `state` - the state to check
+    ///
+    /// # Returns:
+    ///
+    /// false if no match can be reached anymore, so the state is dead
+    fn can_match(&self, state: &Self::State) -> bool;
+}
+
+/// Browses the given radix trie map and lists the keys accepted by the automaton,
+/// in lexicographic order.
+///
+/// # Args:
+///
+/// `automaton` - the automaton accepting the keys
+/// `map` - the radix trie map to browse
+///
+/// # Returns:
+///
+/// the accepted keys with their values
+pub(crate) fn search<'a, A: Automaton, V>(automaton: &A, map: &'a RadixTrieMap<V>) -> Vec<(String, &'a V)> {
+
+    search_tagged(automaton, map, |_| ())
+        .into_iter()
+        .map(|(key, value, _)| (key, value))
+        .collect()
+}
+
+/// Browses the given radix trie map and lists the keys accepted by the automaton,
+/// in lexicographic order, every key being tagged from the state accepting it.
+///
+/// # Args:
+///
+/// `automaton` - the automaton accepting the keys
+/// `map` - the radix trie map to browse
+/// `tag` - computes the tag of an accepted key from the state reached after its last character
+///
+/// # Returns:
+///
+/// the accepted keys with their values and tags
+pub(crate) fn search_tagged<'a, A, V, T, F>(automaton: &A, map: &'a RadixTrieMap<V>, tag: F) -> Vec<(String, &'a V, T)>
+where
+    A: Automaton,
+    F: Fn(&A::State) -> T,
+{
+
+    let mut states = vec![automaton.start()];
+    let mut key = String::new();
+    let mut results = Vec::new();
+
+    if automaton.can_match(&states[0]) {
+        search_node(automaton, &tag, map, &mut key, &mut states, &mut results);
+    }
+
+    results
+}
+
+/// Recursively browse the radix trie, computing the state reached after every character
+/// of the node. The subtree is skipped as soon as the reached state is dead.
+///
+/// # Args:
+///
+/// `automaton` - the automaton accepting the keys
+/// `tag` - computes the tag of an accepted key from its state
+/// `node` - the browsed node
+/// `key` - the characters of the parents
+/// `states` - the states reached by the characters of the parents, starting with the start state
+/// `results` - the accepted keys with their values and tags
+fn search_node<'a, A, V, T, F>(
+    automaton: &A,
+    tag: &F,
+    node: &'a RadixTrieMap<V>,
+    key: &mut String,
+    states: &mut Vec<A::State>,
+    results: &mut Vec<(String, &'a V, T)>,
+)
+where
+    A: Automaton,
+    F: Fn(&A::State) -> T,
+{
+
+    let length = key.len();
+    let depth = states.len();
+
+    let mut pruned = false;
+
+    for character in node.get_characters().chars() {
+
+        let state = automaton.step(&states[states.len() - 1], character);
+        let alive = automaton.can_match(&state);
+
+        key.push(character);
+        states.push(state);
+
+        if !alive {
+            pruned = true;
+            break;
+        }
+    }
+
+    if !pruned {
+
+        if let Some(value) = node.get_value() {
+
+            let state = &states[states.len() - 1];
+
+            if automaton.is_match(state) {
+                results.push((key.clone(), value, tag(state)));
+            }
+        }
+
+        for child in node.get_children() {
+            search_node(automaton, tag, child, key, states, results);
+        }
+    }
+
+    /* the states and characters of the node are removed,
+       so the next sibling starts from the same parent states */
+
+    states.truncate(depth);
+    key.truncate(length);
+}
@@ -0,0 +1,309 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use label::Label;
+use rt::RadixTrieMap;
+
+/// A radix trie map with bit strings keys: the nodes characters are packed bit vectors,
+/// so the nodes can be split between any bits while every byte of a label stores eight bits.
+pub type BitTrieMap<V> = RadixTrieMap<V, Bits>;
+
+/// A vector of bits, packed eight bits per byte from the most significant bit of the first byte.
+/// The unused bits of the last byte are always zero, so the bytes followed by the length
+/// compare as the bits.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bits {
+    bytes: Vec<u8>,
+    length: usize,
+}
+
+/// A borrowed range of bits of some packed bytes. The range may start and end anywhere
+/// into the bytes, so slices of bits are created without copying any byte.
+#[derive(Clone, Copy)]
+pub struct BitSlice<'a> {
+    bytes: &'a [u8],
+    start: usize,
+    end: usize,
+}
+
+impl Bits {
+
+    /// Creates a new empty bit vector.
+    ///
+    /// # Returns:
+    ///
+    /// new bit vector
+    pub fn new() -> Bits {
+        Bits::default()
+    }
+
+    /// Appends a bit to the vector.
+    ///
+    /// # Arguments:
+    ///
+    /// `bit` - the bit to append, true for one
+    pub fn push(&mut self, bit: bool) {
+
+        if self.length % 8 == 0 {
+            self.bytes.push(0);
+        }
+
+        if bit {
+            self.bytes[self.length / 8] |= 0x80 >> (self.length % 8);
+        }
+
+        self.length += 1;
+    }
+
+    /// Getter of a bit of the vector.
+    ///
+    /// # Arguments:
+    ///
+    /// `index` - the index of the bit, from the first bit
+    ///
+    /// # Returns:
+    ///
+    /// the bit, or none if the index is out of the vector
+    pub fn get(&self, index: usize) -> Option<bool> {
+        self.as_bit_slice().get(index)
+    }
+
+    /// Counts the bits of the vector.
+    ///
+    /// # Returns:
+    ///
+    /// the amount of bits
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Indicates if the vector contains no bit.
+    ///
+    /// # Returns:
+    ///
+    /// true if the vector is empty
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Getter of the packed bytes of the vector.
+    ///
+    /// # Returns:
+    ///
+    /// the bytes, the unused bits of the last byte being zero
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Borrows all the bits of the vector.
+    ///
+    /// # Returns:
+    ///
+    /// the slice of the whole vector
+    pub fn as_bit_slice(&self) -> BitSlice<'_> {
+        BitSlice::new(&self.bytes, self.length)
+    }
+}
+
+impl<'a> BitSlice<'a> {
+
+    /// Borrows the leading bits of the given bytes.
+    ///
+    /// # Arguments:
+    ///
+    /// `bytes` - the packed bytes, from the most significant bit of the first byte
+    /// `length` - the amount of leading bits to borrow
+    ///
+    /// # Returns:
+    ///
+    /// the slice of the leading bits
+    ///
+    /// # Panics:
+    ///
+    /// if the bytes have less bits than the given length
+    pub fn new(bytes: &'a [u8], length: usize) -> BitSlice<'a> {
+
+        assert!(length <= bytes.len() * 8, "the bytes have less than {} bits", length);
+
+        BitSlice {
+            bytes,
+            start: 0,
+            end: length,
+        }
+    }
+
+    /// Getter of a bit of the slice.
+    ///
+    /// # Arguments:
+    ///
+    /// `index` - the index of the bit, from the first bit of the slice
+    ///
+    /// # Returns:
+    ///
+    /// the bit, or none if the index is out of the slice
+    pub fn get(&self, index: usize) -> Option<bool> {
+
+        if index >= self.len() {
+            return None;
+        }
+
+        let position = self.start + index;
+
+        Some(self.bytes[position / 8] & (0x80 >> (position % 8)) != 0)
+    }
+
+    /// Counts the bits of the slice.
+    ///
+    /// # Returns:
+    ///
+    /// the amount of bits
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Indicates if the slice contains no bit.
+    ///
+    /// # Returns:
+    ///
+    /// true if the slice is empty
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Creates an iterator over the bits of the slice.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the bits, from the first bit
+    pub fn iter(&self) -> impl Iterator<Item = bool> + 'a {
+
+        let slice = *self;
+        (0..slice.len()).filter_map(move |index| slice.get(index))
+    }
+}
+
+impl Label for Bits {
+
+    type Slice<'a> = BitSlice<'a>;
+    type Unit = bool;
+
+    fn from_slice(slice: BitSlice<'_>) -> Bits {
+
+        let mut bits = Bits {
+            bytes: Vec::with_capacity(slice.len().div_ceil(8)),
+            length: 0,
+        };
+
+        bits.push_slice(slice);
+        bits
+    }
+
+    fn as_slice(&self) -> BitSlice<'_> {
+        self.as_bit_slice()
+    }
+
+    fn first_unit(slice: BitSlice<'_>) -> Option<bool> {
+        slice.get(0)
+    }
+
+    fn split_at<'a>(slice: BitSlice<'a>, index: usize) -> (BitSlice<'a>, BitSlice<'a>) where Self: 'a {
+
+        let middle = slice.start + index;
+
+        let first = BitSlice {
+            end: middle,
+            ..slice
+        };
+
+        let second = BitSlice {
+            start: middle,
+            ..slice
+        };
+
+        (first, second)
+    }
+
+    fn common_length(first: BitSlice<'_>, second: BitSlice<'_>) -> usize {
+
+        let length = first.len().min(second.len());
+
+        (0..length)
+            .find(|&index| first.get(index) != second.get(index))
+            .unwrap_or(length)
+    }
+
+    fn push_slice(&mut self, slice: BitSlice<'_>) {
+
+        for bit in slice.iter() {
+            self.push(bit);
+        }
+    }
+
+    fn truncate(&mut self, length: usize) {
+
+        if length >= self.length {
+            return;
+        }
+
+        self.bytes.truncate(length.div_ceil(8));
+        self.length = length;
+
+        /* the unused bits of the last byte are cleared, so the bytes still compare as the bits */
+
+        if length % 8 != 0 {
+            self.bytes[length / 8] &= 0xff << (8 - length % 8);
+        }
+    }
+
+    fn length(slice: BitSlice<'_>) -> usize {
+        slice.len()
+    }
+}
+
+impl<'a> PartialEq for BitSlice<'a> {
+
+    fn eq(&self, other: &BitSlice<'a>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for BitSlice<'a> {}
+
+impl<'a> PartialOrd for BitSlice<'a> {
+
+    fn partial_cmp(&self, other: &BitSlice<'a>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for BitSlice<'a> {
+
+    /// Compares the bits in lexicographic order: a slice is before the slices it is the beginning of.
+    fn cmp(&self, other: &BitSlice<'a>) -> Ordering {
+
+        let index = Bits::common_length(*self, *other);
+
+        self.get(index)
+            .cmp(&other.get(index))
+    }
+}
+
+impl fmt::Debug for Bits {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_bit_slice().fmt(formatter)
+    }
+}
+
+impl<'a> fmt::Debug for BitSlice<'a> {
+
+    /// Formats the bits as a string of zeros and ones.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+
+        for bit in self.iter() {
+            formatter.write_str(if bit { "1" } else { "0" })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+use std::error::Error;
+use std::fmt;
+use std::mem;
+
+use rt::{
+    self,
+    RadixTrieMap,
+};
+
+/// Builder of a radix trie map from keys given in lexicographic order. Every key is compared
+/// with the previous key only: the nodes of the previous key path which are not shared
+/// with the new key are complete, so they are closed, and the new key characters are
+/// added as a single node. The trie is built in a single pass without browsing it from the root.
+pub struct TrieBuilder<V> {
+    nodes: Vec<OpenNode<V>>,
+    previous: Option<String>,
+}
+
+/// Error returned when the keys given to a builder are not sorted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BuildError {
+
+    /// The key is smaller than the previous key.
+    UnsortedKey(String),
+
+    /// The key is equal to the previous key.
+    DuplicateKey(String),
+}
+
+/// A node of the path of the previous key, which may still get children.
+struct OpenNode<V> {
+    characters: String,
+    value: Option<V>,
+    children: Vec<RadixTrieMap<V>>,
+    end: usize,
+}
+
+/// Computes the length of the common beginning of two words, on a character boundary.
+///
+/// # Args:
+///
+/// `first` - the first word
+/// `second` - the second word
+///
+/// # Returns:
+///
+/// the byte length of the common beginning
+fn common_prefix_length(first: &str, second: &str) -> usize {
+
+    first.char_indices()
+        .zip(second.chars())
+        .find(|&((_, first), second)| first != second)
+        .map(|((index, _), _)| index)
+        .unwrap_or_else(|| first.len().min(second.len()))
+}
+
+impl<V> OpenNode<V> {
+
+    /// Closes the node: it cannot get any other child.
+    ///
+    /// # Returns:
+    ///
+    /// the radix trie map node
+    fn close(self) -> RadixTrieMap<V> {
+        rt::create_node_with_children(self.characters, self.value, self.children)
+    }
+}
+
+impl<V> TrieBuilder<V> {
+
+    /// Creates a new builder without any key.
+    ///
+    /// # Returns:
+    ///
+    /// new builder
+    pub fn new() -> TrieBuilder<V> {
+
+        TrieBuilder {
+            nodes: vec![OpenNode {
+                characters: String::new(),
+                value: None,
+                children: Vec::new(),
+                end: 0,
+            }],
+            previous: None,
+        }
+    }
+
+    /// Adds a key with its value; the key must be bigger than all the previous keys.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to add
+    /// `value` - the value to associate to the key
+    ///
+    /// # Returns:
+    ///
+    /// an error if the key is not bigger than the previous key, in which case the key is not added
+    pub fn push(&mut self, key: &str, value: V) -> Result<(), BuildError> {
+
+        let length = match self.previous {
+            Some(ref previous) if key < previous.as_str() => return Err(BuildError::UnsortedKey(key.to_string())),
+            Some(ref previous) if key == previous => return Err(BuildError::DuplicateKey(key.to_string())),
+            Some(ref previous) => common_prefix_length(previous, key),
+            None => 0,
+        };
+
+        /* the nodes starting after the common beginning only contain smaller keys,
+           so they are complete */
+
+        while self.nodes.len() > 1 && self.nodes[self.nodes.len() - 2].end >= length {
+            self.close_last();
+        }
+
+        let last = self.nodes.len() - 1;
+        let start = self.nodes[last].end - self.nodes[last].characters.len();
+
+        if length < self.nodes[last].end {
+
+            /* the key leaves the last node in the middle of its characters,
+               so the end of the node is moved into a complete child */
+
+            let node = &mut self.nodes[last];
+            let moved = node.characters.split_off(length - start);
+            let child = rt::create_node_with_children(moved, node.value.take(), mem::take(&mut node.children));
+
+            node.children.push(child);
+            node.end = length;
+        }
+
+        if key.len() == length {
+
+            /* only the first key can end at the end of an existing node: the empty key at the root node */
+
+            self.nodes[last].value = Some(value);
+        } else {
+            self.nodes.push(OpenNode {
+                characters: key[length..].to_string(),
+                value: Some(value),
+                children: Vec::new(),
+                end: key.len(),
+            });
+        }
+
+        self.previous = Some(key.to_string());
+
+        Ok(())
+    }
+
+    /// Closes all the nodes and creates the radix trie map.
+    ///
+    /// # Returns:
+    ///
+    /// the radix trie map of all the added keys
+    pub fn build(mut self) -> RadixTrieMap<V> {
+
+        while self.nodes.len() > 1 {
+            self.close_last();
+        }
+
+        self.nodes.pop().map(OpenNode::close).unwrap_or_default()
+    }
+
+    /// Closes the last node of the path and adds it to the children of its parent.
+    fn close_last(&mut self) {
+
+        if let Some(node) = self.nodes.pop() {
+
+            let parent = self.nodes.len() - 1;
+            self.nodes[parent].children.push(node.close());
+        }
+    }
+}
+
+impl<V> Default for TrieBuilder<V> {
+
+    fn default() -> TrieBuilder<V> {
+        TrieBuilder::new()
+    }
+}
+
+impl fmt::Display for BuildError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+
+        match *self {
+            BuildError::UnsortedKey(ref key) => {
+                write!(formatter, "key {:?} is smaller than the previous key", key)
+            },
+            BuildError::DuplicateKey(ref key) => {
+                write!(formatter, "key {:?} is equal to the previous key", key)
+            },
+        }
+    }
+}
+
+impl Error for BuildError {}
@@ -0,0 +1,193 @@
+use std::iter::FromIterator;
+
+use iter::Keys;
+use rt::RadixTrieMap;
+
+/// A radix trie map with byte strings keys: the nodes characters are byte vectors,
+/// so the keys have no UTF-8 requirement and the nodes can be split between any bytes.
+pub type ByteTrieMap<V> = RadixTrieMap<V, Vec<u8>>;
+
+/// A radix trie storing byte strings (hashes, binary identifiers...) without any associated value.
+#[derive(Clone, Default)]
+pub struct ByteTrie {
+    map: ByteTrieMap<()>,
+}
+
+impl ByteTrie {
+
+    /// Creates a new empty byte radix trie.
+    ///
+    /// # Returns:
+    ///
+    /// new byte radix trie
+    pub fn new() -> ByteTrie {
+
+        ByteTrie {
+            map: ByteTrieMap::default(),
+        }
+    }
+
+    /// Inserts a new key into the byte radix trie (may create new nodes).
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the new key to store
+    ///
+    /// # Returns:
+    ///
+    /// true if the key was not already stored
+    pub fn insert(&mut self, key: &[u8]) -> bool {
+        self.map.insert(key, ()).is_none()
+    }
+
+    /// Counts the keys stored into the byte radix trie.
+    ///
+    /// # Returns:
+    ///
+    /// the amount of stored keys
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Indicates if the byte radix trie contains no key.
+    ///
+    /// # Returns:
+    ///
+    /// true if no key is stored
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Removes all the keys from the byte radix trie.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Creates an iterator over all the keys, in lexicographic order of the bytes.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the keys
+    pub fn iter(&self) -> Keys<'_, (), Vec<u8>> {
+        self.map.keys()
+    }
+
+    /// Creates an iterator over the keys starting with the given prefix, in lexicographic order of the bytes.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the beginning of the keys to iterate over
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the keys
+    pub fn iter_prefix(&self, prefix: &[u8]) -> Keys<'_, (), Vec<u8>> {
+        Keys::new(self.map.iter_prefix(prefix))
+    }
+
+    /// Searches for the longest stored key which is a prefix of the given query.
+    ///
+    /// # Arguments:
+    ///
+    /// `query` - the bytes to match
+    ///
+    /// # Returns:
+    ///
+    /// the longest key (as a part of the query), or none if no key is a prefix of the query
+    pub fn longest_prefix<'a>(&self, query: &'a [u8]) -> Option<&'a [u8]> {
+        self.map.longest_prefix(query).map(|(key, _)| key)
+    }
+
+    /// Removes a key from the byte radix trie (may remove or merge nodes).
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to remove
+    ///
+    /// # Returns:
+    ///
+    /// true if the key was stored
+    pub fn remove(&mut self, key: &[u8]) -> bool {
+        self.map.remove(key).is_some()
+    }
+
+    /// Indicates if some bytes exist into the byte radix trie, either as a stored key
+    /// or as the beginning of a stored key (same as `has_prefix`)
+    ///
+    /// # Arguments:
+    ///
+    /// `bytes` - the bytes to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if the bytes exist, False if they do not exist
+    pub fn exists(&self, bytes: &[u8]) -> bool {
+        self.map.has_prefix(bytes)
+    }
+
+    /// Indicates if the given key has been inserted into the byte radix trie.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if the key has been inserted, False if it has not
+    pub fn contains_key(&self, key: &[u8]) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Indicates if at least one stored key starts with the given prefix.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the beginning of the keys to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if one key at least starts with the prefix, False otherwise
+    pub fn has_prefix(&self, prefix: &[u8]) -> bool {
+        self.map.has_prefix(prefix)
+    }
+
+    /// Getter of the children of the root node.
+    ///
+    /// # Returns:
+    ///
+    /// list of children
+    pub fn get_children(&self) -> &Vec<ByteTrieMap<()>> {
+        self.map.get_children()
+    }
+}
+
+impl<'a> FromIterator<&'a [u8]> for ByteTrie {
+
+    fn from_iter<I: IntoIterator<Item = &'a [u8]>>(iter: I) -> ByteTrie {
+
+        let mut trie = ByteTrie::new();
+        trie.extend(iter);
+
+        trie
+    }
+}
+
+impl<'a> Extend<&'a [u8]> for ByteTrie {
+
+    fn extend<I: IntoIterator<Item = &'a [u8]>>(&mut self, iter: I) {
+
+        for key in iter {
+            self.insert(key);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a ByteTrie {
+
+    type Item = Vec<u8>;
+    type IntoIter = Keys<'a, (), Vec<u8>>;
+
+    fn into_iter(self) -> Keys<'a, (), Vec<u8>> {
+        self.iter()
+    }
+}
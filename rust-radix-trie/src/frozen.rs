@@ -0,0 +1,646 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::str;
+
+use rt::RadixTrieMap;
+
+/// Magic bytes starting every frozen radix trie.
+const MAGIC: &[u8; 4] = b"RDXT";
+
+/// Version of the binary format, incremented for every incompatible change.
+const VERSION: u32 = 1;
+
+/// Length of the header: magic bytes, version, amount of words, root node offset and checksum.
+const HEADER_LENGTH: usize = 20;
+
+/// Position of the checksum into the header; the checksum covers all the other bytes.
+const CHECKSUM_POSITION: usize = 16;
+
+/// The checksum of no bytes at all.
+const CHECKSUM_BASIS: u32 = 0x811c_9dc5;
+
+/// A read-only radix trie, queried directly from its binary form without creating any node.
+/// The binary form is created by `RadixTrie::to_bytes`, and can be stored into a file,
+/// then loaded or memory-mapped at startup.
+///
+/// The binary form is a header followed by the node records, children before their parent:
+/// every record is a terminal flag byte, the length of the node characters, the characters,
+/// the amount of children and the offsets of the children records. All the integers
+/// are 32 bits little-endian, so the binary form does not depend on the platform.
+#[derive(Clone, Copy)]
+pub struct FrozenRadixTrie<'a> {
+    body: &'a [u8],
+    root: usize,
+    count: usize,
+}
+
+/// Error returned when bytes cannot be loaded as a frozen radix trie.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FormatError {
+
+    /// The bytes do not start with the magic bytes of the format.
+    InvalidMagic,
+
+    /// The bytes have been created with an unknown version of the format.
+    UnsupportedVersion(u32),
+
+    /// The bytes are shorter than the header.
+    Truncated,
+
+    /// The checksum does not match the bytes, which have been modified or partially written.
+    ChecksumMismatch,
+
+    /// The node record at the given offset of the body is invalid.
+    InvalidNode(usize),
+
+    /// The amount of words of the header is not the amount of terminal node records.
+    CountMismatch,
+
+    /// A length or an offset of the radix trie does not fit into the 32 bits integers of the format.
+    TooLarge,
+}
+
+/// Iterator over the words of a frozen radix trie, in lexicographic order.
+pub struct FrozenIter<'a> {
+    trie: FrozenRadixTrie<'a>,
+    nodes: Vec<(usize, usize)>,
+    key: String,
+}
+
+/// A node record read from the binary form.
+#[derive(Clone, Copy)]
+struct FrozenNode<'a> {
+    characters: &'a str,
+    terminal: bool,
+    children: &'a [u8],
+}
+
+/// Computes the FNV-1a checksum of the given bytes.
+///
+/// # Args:
+///
+/// `checksum` - the checksum of the previous bytes
+/// `bytes` - the bytes to add to the checksum
+///
+/// # Returns:
+///
+/// the checksum of all the bytes
+fn checksum(checksum: u32, bytes: &[u8]) -> u32 {
+
+    bytes.iter().fold(checksum, |checksum, &byte| {
+        (checksum ^ byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// Reads a 32 bits little-endian integer.
+///
+/// # Args:
+///
+/// `bytes` - the bytes to read
+/// `position` - the position of the integer
+///
+/// # Returns:
+///
+/// the integer, or none if the bytes are too short
+fn read_u32(bytes: &[u8], position: usize) -> Option<usize> {
+
+    let integer = bytes.get(position..position.checked_add(4)?)?;
+
+    Some(u32::from_le_bytes([integer[0], integer[1], integer[2], integer[3]]) as usize)
+}
+
+/// Appends a 32 bits little-endian integer.
+///
+/// # Args:
+///
+/// `bytes` - the bytes to write into
+/// `integer` - the integer to write
+///
+/// # Returns:
+///
+/// an error if the integer is bigger than the biggest 32 bits integer
+pub(crate) fn write_u32(bytes: &mut Vec<u8>, integer: usize) -> Result<(), FormatError> {
+
+    let integer = u32::try_from(integer).map_err(|_| FormatError::TooLarge)?;
+
+    bytes.extend_from_slice(&integer.to_le_bytes());
+    Ok(())
+}
+
+/// Recursively writes the records of the given node and of its subtree, children first.
+///
+/// # Args:
+///
+/// `node` - the node to write
+/// `body` - the records written so far
+///
+/// # Returns:
+///
+/// the offset of the node record, or an error if the records are too big for the format
+fn write_node(node: &RadixTrieMap<()>, body: &mut Vec<u8>) -> Result<usize, FormatError> {
+
+    let children = node.get_children()
+        .iter()
+        .map(|child| write_node(child, body))
+        .collect::<Result<Vec<usize>, FormatError>>()?;
+
+    let offset = body.len();
+
+    body.push(node.is_terminal() as u8);
+    write_u32(body, node.get_characters().len())?;
+    body.extend_from_slice(node.get_characters().as_bytes());
+    write_u32(body, children.len())?;
+
+    for child in children {
+        write_u32(body, child)?;
+    }
+
+    Ok(offset)
+}
+
+/// Creates the binary form of a radix trie.
+///
+/// # Args:
+///
+/// `root` - the root node of the radix trie
+///
+/// # Returns:
+///
+/// the header followed by the node records, or an error if the radix trie is too big for the format
+pub(crate) fn encode(root: &RadixTrieMap<()>) -> Result<Vec<u8>, FormatError> {
+
+    let mut body = Vec::new();
+    let root_offset = write_node(root, &mut body)?;
+
+    let mut bytes = Vec::with_capacity(HEADER_LENGTH + body.len());
+
+    bytes.extend_from_slice(MAGIC);
+    write_u32(&mut bytes, VERSION as usize)?;
+    write_u32(&mut bytes, root.len())?;
+    write_u32(&mut bytes, root_offset)?;
+
+    let sum = checksum(checksum(CHECKSUM_BASIS, &bytes), &body);
+
+    write_u32(&mut bytes, sum as usize)?;
+    bytes.extend_from_slice(&body);
+
+    Ok(bytes)
+}
+
+impl<'a> FrozenNode<'a> {
+
+    /// Reads the node record at the given offset.
+    ///
+    /// # Args:
+    ///
+    /// `body` - the node records
+    /// `offset` - the offset of the record to read
+    ///
+    /// # Returns:
+    ///
+    /// the node with the offset of the end of its record, or none if the record is invalid
+    fn read(body: &'a [u8], offset: usize) -> Option<(FrozenNode<'a>, usize)> {
+
+        let terminal = match *body.get(offset)? {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+
+        let length = read_u32(body, offset + 1)?;
+        let start = offset + 5;
+        let characters = str::from_utf8(body.get(start..start.checked_add(length)?)?).ok()?;
+
+        let count = read_u32(body, start + length)?;
+        let start = start + length + 4;
+        let end = start.checked_add(count.checked_mul(4)?)?;
+
+        let node = FrozenNode {
+            characters,
+            terminal,
+            children: body.get(start..end)?,
+        };
+
+        Some((node, end))
+    }
+
+    /// Getter of the offset of a child record.
+    ///
+    /// # Args:
+    ///
+    /// `index` - the index of the child, the children being sorted by their first character
+    ///
+    /// # Returns:
+    ///
+    /// the offset of the child record
+    fn child(&self, index: usize) -> usize {
+        read_u32(self.children, index * 4).unwrap_or(usize::MAX)
+    }
+
+    /// Getter of the offsets of the children records, sorted by the first character of the children.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the children offsets
+    fn children(&self) -> impl DoubleEndedIterator<Item = usize> + 'a {
+        self.children
+            .chunks(4)
+            .map(|offset| u32::from_le_bytes([offset[0], offset[1], offset[2], offset[3]]) as usize)
+    }
+}
+
+impl<'a> FrozenRadixTrie<'a> {
+
+    /// Loads a frozen radix trie from its binary form. The header and the checksum are checked,
+    /// and the node records are read once to check they are well formed; no node is created.
+    ///
+    /// # Arguments:
+    ///
+    /// `bytes` - the binary form created by `RadixTrie::to_bytes`
+    ///
+    /// # Returns:
+    ///
+    /// the frozen radix trie, or an error if the bytes are not a valid binary form
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<FrozenRadixTrie<'a>, FormatError> {
+
+        if bytes.len() < HEADER_LENGTH {
+            return Err(if bytes.starts_with(MAGIC) { FormatError::Truncated } else { FormatError::InvalidMagic });
+        }
+
+        if &bytes[..4] != MAGIC {
+            return Err(FormatError::InvalidMagic);
+        }
+
+        let version = read_u32(bytes, 4).unwrap_or(0) as u32;
+
+        if version != VERSION {
+            return Err(FormatError::UnsupportedVersion(version));
+        }
+
+        let (header, body) = bytes.split_at(HEADER_LENGTH);
+        let sum = checksum(checksum(CHECKSUM_BASIS, &header[..CHECKSUM_POSITION]), body);
+
+        if read_u32(header, CHECKSUM_POSITION) != Some(sum as usize) {
+            return Err(FormatError::ChecksumMismatch);
+        }
+
+        let trie = FrozenRadixTrie {
+            body,
+            root: read_u32(header, 12).unwrap_or(0),
+            count: read_u32(header, 8).unwrap_or(0),
+        };
+
+        trie.check_records()?;
+
+        Ok(trie)
+    }
+
+    /// Reads all the node records in order, and checks they form the tree of a radix trie:
+    /// every child offset is the offset of a record before its parent, so the browsing of the trie
+    /// always terminates, every record but the root is the child of a single parent, and the
+    /// children of every node start with strictly increasing characters, as they are binary searched.
+    /// The last record is the root node, and the terminal records are the words counted by the header.
+    ///
+    /// # Returns:
+    ///
+    /// an error with the offset of the first invalid record, or a count mismatch
+    fn check_records(&self) -> Result<(), FormatError> {
+
+        /* the offset, the first character and the parent flag of every record read so far,
+           sorted by offset as the records are read in order */
+
+        let mut records: Vec<(usize, Option<char>, bool)> = Vec::new();
+        let mut terminals = 0;
+        let mut offset = 0;
+
+        while offset < self.body.len() {
+
+            let (node, end) = FrozenNode::read(self.body, offset).ok_or(FormatError::InvalidNode(offset))?;
+            let mut previous = None;
+
+            for child in node.children() {
+
+                let record = records
+                    .binary_search_by_key(&child, |&(start, _, _)| start)
+                    .ok()
+                    .map(|index| &mut records[index]);
+
+                let character = match record {
+                    Some(&mut (_, Some(character), ref mut parent)) if !*parent => {
+                        *parent = true;
+                        character
+                    },
+                    _ => return Err(FormatError::InvalidNode(offset)),
+                };
+
+                if previous >= Some(character) {
+                    return Err(FormatError::InvalidNode(offset));
+                }
+
+                previous = Some(character);
+            }
+
+            if node.terminal {
+                terminals += 1;
+            }
+
+            records.push((offset, node.characters.chars().next(), false));
+            offset = end;
+        }
+
+        let children = match records.split_last() {
+            Some((&(root, _, _), children)) if root == self.root => children,
+            _ => return Err(FormatError::InvalidNode(self.root)),
+        };
+
+        if let Some(&(orphan, _, _)) = children.iter().find(|&&(_, _, parent)| !parent) {
+            return Err(FormatError::InvalidNode(orphan));
+        }
+
+        if terminals != self.count {
+            return Err(FormatError::CountMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Reads the node record at the given offset; the records have been checked when loaded.
+    ///
+    /// # Args:
+    ///
+    /// `offset` - the offset of the record
+    ///
+    /// # Returns:
+    ///
+    /// the node, or none if the offset is not the offset of a record
+    fn node(&self, offset: usize) -> Option<FrozenNode<'a>> {
+        FrozenNode::read(self.body, offset).map(|(node, _)| node)
+    }
+
+    /// Searches for the child of the given node starting with the same character as the given word.
+    /// Children are sorted by their first character, which is different for every child.
+    ///
+    /// # Args:
+    ///
+    /// `offset` - the offset of the parent node
+    /// `node` - the parent node
+    /// `word` - the word to compare with the children characters
+    ///
+    /// # Returns:
+    ///
+    /// the offset of the child and the child, or none if no child starts with the first character of the word
+    fn find_child(&self, offset: usize, node: &FrozenNode<'a>, word: &str) -> Option<(usize, FrozenNode<'a>)> {
+
+        let first = word.chars().next()?;
+
+        let mut low = 0;
+        let mut high = node.children.len() / 4;
+
+        while low < high {
+
+            let middle = (low + high) / 2;
+            let child_offset = node.child(middle);
+
+            /* the children records are always before their parent record,
+               which guarantees the browsing terminates even with crafted bytes */
+
+            if child_offset >= offset {
+                return None;
+            }
+
+            let child = self.node(child_offset)?;
+
+            match child.characters.chars().next() {
+                Some(character) if character == first => return Some((child_offset, child)),
+                Some(character) if character < first => low = middle + 1,
+                _ => high = middle,
+            }
+        }
+
+        None
+    }
+
+    /// Browses the trie in order to find the node where the given prefix ends.
+    /// The prefix may end in the middle of the node characters.
+    ///
+    /// # Args:
+    ///
+    /// `prefix` - the prefix to search for
+    ///
+    /// # Returns:
+    ///
+    /// the offset of the node with the length of the prefix part stored into
+    /// the parents of the node, or none if no word starts with the prefix
+    fn prefix_node(&self, prefix: &str) -> Option<(usize, usize)> {
+
+        let mut offset = self.root;
+        let mut node = self.node(offset)?;
+        let mut length = 0;
+
+        loop {
+
+            let rest = &prefix[length..];
+
+            if node.characters.starts_with(rest) {
+                return Some((offset, length));
+            }
+
+            if !rest.starts_with(node.characters) {
+                return None;
+            }
+
+            length += node.characters.len();
+
+            let (child_offset, child) = self.find_child(offset, &node, &prefix[length..])?;
+
+            offset = child_offset;
+            node = child;
+        }
+    }
+
+    /// Counts the words stored into the frozen radix trie.
+    ///
+    /// # Returns:
+    ///
+    /// the amount of stored words
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Indicates if the frozen radix trie contains no word.
+    ///
+    /// # Returns:
+    ///
+    /// true if no word is stored
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Indicates if a word exists into the frozen radix trie, either as a stored key
+    /// or as the beginning of a stored key.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if the word exists, False if the word does not exist
+    pub fn exists(&self, word: &str) -> bool {
+
+        /* only the root node may have neither value nor children */
+
+        !self.is_empty() && self.prefix_node(word).is_some()
+    }
+
+    /// Indicates if the given word has been inserted as a key into the radix trie
+    /// the frozen radix trie has been created from.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if the word has been inserted, False if it has not
+    pub fn contains_key(&self, word: &str) -> bool {
+
+        self.prefix_node(word)
+            .and_then(|(offset, length)| self.node(offset).map(|node| (node, length)))
+            .is_some_and(|(node, length)| node.terminal && length + node.characters.len() == word.len())
+    }
+
+    /// Searches for the longest stored word which is a prefix of the given query.
+    ///
+    /// # Arguments:
+    ///
+    /// `query` - the word to match
+    ///
+    /// # Returns:
+    ///
+    /// the longest word (as a part of the query), or none if no word is a prefix of the query
+    pub fn longest_prefix<'b>(&self, query: &'b str) -> Option<&'b str> {
+
+        let mut offset = self.root;
+        let mut node = self.node(offset)?;
+        let mut length = 0;
+        let mut longest = None;
+
+        loop {
+
+            if !query[length..].starts_with(node.characters) {
+                break;
+            }
+
+            length += node.characters.len();
+
+            if node.terminal {
+                longest = Some(length);
+            }
+
+            match self.find_child(offset, &node, &query[length..]) {
+                Some((child_offset, child)) => {
+                    offset = child_offset;
+                    node = child;
+                },
+                None => break,
+            }
+        }
+
+        longest.map(|length| &query[..length])
+    }
+
+    /// Creates an iterator over all the words, in lexicographic order.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the words
+    pub fn iter(&self) -> FrozenIter<'a> {
+        self.iter_prefix("")
+    }
+
+    /// Creates an iterator over the words starting with the given prefix, in lexicographic order.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the beginning of the words to iterate over
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the words
+    pub fn iter_prefix(&self, prefix: &str) -> FrozenIter<'a> {
+
+        let (nodes, key) = match self.prefix_node(prefix) {
+            Some((offset, length)) => (vec![(offset, length)], prefix[..length].to_string()),
+            None => (Vec::new(), String::new()),
+        };
+
+        FrozenIter {
+            trie: *self,
+            nodes,
+            key,
+        }
+    }
+}
+
+impl<'a> Iterator for FrozenIter<'a> {
+
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+
+        while let Some((offset, length)) = self.nodes.pop() {
+
+            let node = match self.trie.node(offset) {
+                Some(node) => node,
+                None => continue,
+            };
+
+            self.key.truncate(length);
+            self.key.push_str(node.characters);
+
+            let length = self.key.len();
+
+            self.nodes.extend(
+                node.children()
+                    .rev()
+                    .map(|child| (child, length))
+            );
+
+            if node.terminal {
+                return Some(self.key.clone());
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a> IntoIterator for &FrozenRadixTrie<'a> {
+
+    type Item = String;
+    type IntoIter = FrozenIter<'a>;
+
+    fn into_iter(self) -> FrozenIter<'a> {
+        self.iter()
+    }
+}
+
+impl fmt::Display for FormatError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+
+        match *self {
+            FormatError::InvalidMagic => write!(formatter, "not a frozen radix trie"),
+            FormatError::UnsupportedVersion(version) => write!(formatter, "unsupported format version {}", version),
+            FormatError::Truncated => write!(formatter, "truncated frozen radix trie"),
+            FormatError::ChecksumMismatch => write!(formatter, "checksum mismatch"),
+            FormatError::InvalidNode(offset) => write!(formatter, "invalid node record at offset {}", offset),
+            FormatError::CountMismatch => write!(formatter, "words count mismatch"),
+            FormatError::TooLarge => write!(formatter, "radix trie too big for the binary format"),
+        }
+    }
+}
+
+impl Error for FormatError {}
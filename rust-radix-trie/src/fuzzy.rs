@@ -0,0 +1,135 @@
+use automaton::{
+    self,
+    Automaton,
+};
+use rt::RadixTrieMap;
+
+/// The edit distance used to compare the stored keys with a fuzzy search query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Distance {
+
+    /// Insertions, deletions and substitutions of characters.
+    Levenshtein,
+
+    /// Insertions, deletions, substitutions and transpositions of two adjacent characters
+    /// (optimal string alignment: a transposed substring is not edited again).
+    Damerau,
+}
+
+/// Search of the keys within an edit distance of a query, as an automaton whose state is
+/// the row of the distance matrix of the read characters: the rows of a node characters
+/// are computed once, then shared by all the keys of the node subtree.
+pub(crate) struct FuzzySearch {
+    query: Vec<char>,
+    max_distance: usize,
+    distance: Distance,
+}
+
+/// The state of a fuzzy search after reading some characters of a key.
+pub(crate) struct FuzzyState {
+    row: Vec<usize>,
+    previous_row: Vec<usize>,
+    previous: Option<char>,
+}
+
+impl FuzzySearch {
+
+    /// Creates a new search of the keys close to the given query.
+    ///
+    /// # Args:
+    ///
+    /// `query` - the word to compare the keys with
+    /// `max_distance` - the maximum distance of the found keys
+    /// `distance` - the edit distance to use
+    ///
+    /// # Returns:
+    ///
+    /// new search
+    pub(crate) fn new(query: &str, max_distance: usize, distance: Distance) -> FuzzySearch {
+
+        FuzzySearch {
+            query: query.chars().collect(),
+            max_distance,
+            distance,
+        }
+    }
+
+    /// Browses the given radix trie map and lists the keys close to the query,
+    /// sorted by distance then in lexicographic order.
+    ///
+    /// # Args:
+    ///
+    /// `map` - the radix trie map to browse
+    ///
+    /// # Returns:
+    ///
+    /// the found keys with their values and distances
+    pub(crate) fn search<'a, V>(&self, map: &'a RadixTrieMap<V>) -> Vec<(String, &'a V, usize)> {
+
+        let mut results = automaton::search_tagged(self, map, |state| state.row[self.query.len()]);
+
+        /* the keys are found in lexicographic order and the sort is stable,
+           so keys with the same distance stay sorted */
+
+        results.sort_by_key(|&(_, _, distance)| distance);
+        results
+    }
+}
+
+impl Automaton for FuzzySearch {
+
+    type State = FuzzyState;
+
+    fn start(&self) -> FuzzyState {
+
+        FuzzyState {
+            row: (0..self.query.len() + 1).collect(),
+            previous_row: Vec::new(),
+            previous: None,
+        }
+    }
+
+    /// Computes the distances between the query prefixes and the key completed with the given character.
+    fn step(&self, state: &FuzzyState, character: char) -> FuzzyState {
+
+        let last_row = &state.row;
+
+        let mut row = Vec::with_capacity(last_row.len());
+        row.push(last_row[0] + 1);
+
+        for (index, &query_character) in self.query.iter().enumerate() {
+
+            let substitution = if query_character == character { 0 } else { 1 };
+
+            let mut distance = (last_row[index] + substitution)
+                .min(last_row[index + 1] + 1)
+                .min(row[index] + 1);
+
+            let transposed = index > 0 &&
+                self.distance == Distance::Damerau &&
+                state.previous == Some(query_character) &&
+                self.query[index - 1] == character;
+
+            if transposed {
+                distance = distance.min(state.previous_row[index - 1] + 1);
+            }
+
+            row.push(distance);
+        }
+
+        FuzzyState {
+            row,
+            previous_row: if self.distance == Distance::Damerau { last_row.clone() } else { Vec::new() },
+            previous: Some(character),
+        }
+    }
+
+    fn is_match(&self, state: &FuzzyState) -> bool {
+        state.row[self.query.len()] <= self.max_distance
+    }
+
+    /// The distances of the next rows cannot be smaller than the minimum of the row.
+    fn can_match(&self, state: &FuzzyState) -> bool {
+        state.row.iter().cloned().min().unwrap_or(0) <= self.max_distance
+    }
+}
@@ -0,0 +1,88 @@
+use automaton::Automaton;
+
+/// Pattern matching the keys with wildcards: `?` matches any single character
+/// and `*` matches any run of characters (including an empty one).
+/// The state of the matcher is the set of pattern positions reached
+/// after reading the characters of a key.
+pub(crate) struct Glob {
+    pattern: Vec<char>,
+}
+
+impl Glob {
+
+    /// Creates a new matcher of the given pattern.
+    ///
+    /// # Args:
+    ///
+    /// `pattern` - the pattern with wildcards
+    ///
+    /// # Returns:
+    ///
+    /// new matcher
+    pub(crate) fn new(pattern: &str) -> Glob {
+
+        Glob {
+            pattern: pattern.chars().collect(),
+        }
+    }
+
+    /// Marks the positions after the stars of the reached positions as reached too,
+    /// as stars can match an empty run of characters.
+    ///
+    /// # Args:
+    ///
+    /// `positions` - the reached positions to complete
+    fn skip_stars(&self, positions: &mut [bool]) {
+
+        for (index, &pattern_character) in self.pattern.iter().enumerate() {
+
+            if positions[index] && pattern_character == '*' {
+                positions[index + 1] = true;
+            }
+        }
+    }
+}
+
+impl Automaton for Glob {
+
+    type State = Vec<bool>;
+
+    fn start(&self) -> Vec<bool> {
+
+        let mut positions = vec![false; self.pattern.len() + 1];
+        positions[0] = true;
+
+        self.skip_stars(&mut positions);
+        positions
+    }
+
+    fn step(&self, positions: &Vec<bool>, character: char) -> Vec<bool> {
+
+        let mut next_positions = vec![false; positions.len()];
+
+        for (index, &pattern_character) in self.pattern.iter().enumerate() {
+
+            if !positions[index] {
+                continue;
+            }
+
+            match pattern_character {
+                '*' => next_positions[index] = true,
+                '?' => next_positions[index + 1] = true,
+                _ if pattern_character == character => next_positions[index + 1] = true,
+                _ => {},
+            }
+        }
+
+        self.skip_stars(&mut next_positions);
+        next_positions
+    }
+
+    fn is_match(&self, positions: &Vec<bool>) -> bool {
+        positions[self.pattern.len()]
+    }
+
+    fn can_match(&self, positions: &Vec<bool>) -> bool {
+        positions.contains(&true)
+    }
+}
@@ -0,0 +1,476 @@
+use std::error::Error;
+use std::fmt;
+use std::iter::FromIterator;
+use std::net::{
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr,
+};
+use std::str::FromStr;
+
+use bits::{
+    BitSlice,
+    BitTrieMap,
+    Bits,
+};
+use iter::Iter;
+
+/// An IP network in CIDR notation (`10.0.0.0/8`, `2001:db8::/32`): an address
+/// and the amount of leading bits of the address which are part of the network.
+/// The bits of the address after the prefix length are always zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IpPrefix {
+    address: IpAddr,
+    length: u8,
+}
+
+/// Error returned when an IP prefix cannot be created or parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PrefixError {
+
+    /// The address part is not a valid IPv4 or IPv6 address.
+    InvalidAddress,
+
+    /// The prefix length is not a number, or is longer than the address (32 bits for IPv4, 128 bits for IPv6).
+    InvalidLength,
+}
+
+/// A routing table of IP prefixes with their values (routes, firewall rules...),
+/// searched with longest-prefix match. The prefixes are stored into radix trie maps
+/// labelled with packed bits, so the nodes can be split at any bit of the addresses.
+/// IPv4 and IPv6 prefixes are stored into two different tries.
+#[derive(Clone)]
+pub struct IpPrefixTable<V> {
+    v4: BitTrieMap<V>,
+    v6: BitTrieMap<V>,
+}
+
+/// Iterator over the prefixes and values of a table: the IPv4 prefixes first, then the IPv6 prefixes,
+/// each prefix followed by the prefixes it contains.
+pub struct IpPrefixIter<'a, V: 'a> {
+    v4: Iter<'a, V, Bits>,
+    v6: Iter<'a, V, Bits>,
+}
+
+/// Getter of the octets of an address, the octets of an IPv4 address being followed by zeros.
+///
+/// # Args:
+///
+/// `address` - the address to read
+///
+/// # Returns:
+///
+/// the octets of the address with its amount of bits
+fn address_octets(address: IpAddr) -> ([u8; 16], u8) {
+
+    let mut octets = [0; 16];
+
+    match address {
+        IpAddr::V4(address) => {
+            octets[..4].copy_from_slice(&address.octets());
+            (octets, 32)
+        },
+        IpAddr::V6(address) => (address.octets(), 128),
+    }
+}
+
+impl IpPrefix {
+
+    /// Creates a new prefix; the bits of the address after the prefix length are cleared.
+    ///
+    /// # Arguments:
+    ///
+    /// `address` - an address of the network
+    /// `length` - the amount of leading bits of the network
+    ///
+    /// # Returns:
+    ///
+    /// the new prefix, or an error if the length is longer than the address
+    pub fn new(address: IpAddr, length: u8) -> Result<IpPrefix, PrefixError> {
+
+        let (_, maximum_length) = address_octets(address);
+
+        if length > maximum_length {
+            return Err(PrefixError::InvalidLength);
+        }
+
+        Ok(IpPrefix::masked(address, length))
+    }
+
+    /// Getter of the network address.
+    ///
+    /// # Returns:
+    ///
+    /// the address, with the bits after the prefix length cleared
+    pub fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    /// Getter of the prefix length.
+    ///
+    /// # Returns:
+    ///
+    /// the amount of leading bits of the network
+    pub fn length(&self) -> u8 {
+        self.length
+    }
+
+    /// Indicates if the given address belongs to the network.
+    ///
+    /// # Arguments:
+    ///
+    /// `address` - the address to check
+    ///
+    /// # Returns:
+    ///
+    /// True if the address starts with the prefix (and has the same version), False otherwise
+    pub fn contains(&self, address: IpAddr) -> bool {
+        address.is_ipv4() == self.address.is_ipv4()
+            && IpPrefix::masked(address, self.length) == *self
+    }
+
+    /// Getter of the octets of the prefix address.
+    ///
+    /// # Returns:
+    ///
+    /// the octets of the address, the octets of an IPv4 address being followed by zeros
+    fn octets(&self) -> [u8; 16] {
+        address_octets(self.address).0
+    }
+
+    /// Creates a prefix from an address whose bits after the prefix length are cleared.
+    ///
+    /// # Args:
+    ///
+    /// `address` - an address of the network
+    /// `length` - the amount of leading bits of the network (not longer than the address)
+    ///
+    /// # Returns:
+    ///
+    /// new prefix
+    fn masked(address: IpAddr, length: u8) -> IpPrefix {
+
+        let (mut octets, _) = address_octets(address);
+
+        for (index, octet) in octets.iter_mut().enumerate() {
+
+            let kept = (length as usize).saturating_sub(index * 8).min(8);
+            *octet &= !(0xff_u8.checked_shr(kept as u32).unwrap_or(0));
+        }
+
+        IpPrefix::from_octets(address.is_ipv4(), octets, length)
+    }
+
+    /// Creates a prefix from the bits stored into a table.
+    ///
+    /// # Args:
+    ///
+    /// `v4` - true for an IPv4 prefix, false for an IPv6 prefix
+    /// `bits` - the bits of the prefix
+    ///
+    /// # Returns:
+    ///
+    /// new prefix
+    fn from_bits(v4: bool, bits: &Bits) -> IpPrefix {
+
+        let mut octets = [0; 16];
+        octets[..bits.as_bytes().len()].copy_from_slice(bits.as_bytes());
+
+        IpPrefix::from_octets(v4, octets, bits.len() as u8)
+    }
+
+    /// Creates a prefix from the octets of its address.
+    ///
+    /// # Args:
+    ///
+    /// `v4` - true for an IPv4 prefix, false for an IPv6 prefix
+    /// `octets` - the octets of the address, the bits after the prefix length being zero
+    /// `length` - the amount of leading bits of the network
+    ///
+    /// # Returns:
+    ///
+    /// new prefix
+    fn from_octets(v4: bool, octets: [u8; 16], length: u8) -> IpPrefix {
+
+        let address = if v4 {
+            IpAddr::V4(Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3]))
+        } else {
+            IpAddr::V6(Ipv6Addr::from(octets))
+        };
+
+        IpPrefix {
+            address,
+            length,
+        }
+    }
+}
+
+impl FromStr for IpPrefix {
+
+    type Err = PrefixError;
+
+    /// Parses a prefix in CIDR notation; an address without length is a single host prefix.
+    fn from_str(text: &str) -> Result<IpPrefix, PrefixError> {
+
+        let (address, length) = match text.find('/') {
+            Some(index) => (&text[..index], Some(&text[index + 1..])),
+            None => (text, None),
+        };
+
+        let address: IpAddr = address.parse().map_err(|_| PrefixError::InvalidAddress)?;
+
+        let length = match length {
+            Some(length) => length.parse().map_err(|_| PrefixError::InvalidLength)?,
+            None if address.is_ipv4() => 32,
+            None => 128,
+        };
+
+        IpPrefix::new(address, length)
+    }
+}
+
+impl fmt::Display for IpPrefix {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}/{}", self.address, self.length)
+    }
+}
+
+impl fmt::Display for PrefixError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+
+        match *self {
+            PrefixError::InvalidAddress => write!(formatter, "invalid IP address"),
+            PrefixError::InvalidLength => write!(formatter, "invalid prefix length"),
+        }
+    }
+}
+
+impl Error for PrefixError {}
+
+impl<V> IpPrefixTable<V> {
+
+    /// Creates a new empty prefix table.
+    ///
+    /// # Returns:
+    ///
+    /// new prefix table
+    pub fn new() -> IpPrefixTable<V> {
+
+        IpPrefixTable {
+            v4: BitTrieMap::default(),
+            v6: BitTrieMap::default(),
+        }
+    }
+
+    /// Inserts a prefix with its value into the table.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the prefix to store
+    /// `value` - the value to associate to the prefix
+    ///
+    /// # Returns:
+    ///
+    /// the previous value of the prefix if the prefix was already stored, none otherwise
+    pub fn insert(&mut self, prefix: IpPrefix, value: V) -> Option<V> {
+        self.trie_mut(prefix.address).insert(BitSlice::new(&prefix.octets(), prefix.length as usize), value)
+    }
+
+    /// Getter of the value associated to the given prefix (exact match).
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the prefix to search for
+    ///
+    /// # Returns:
+    ///
+    /// the value of the prefix or none if the prefix is not stored
+    pub fn get(&self, prefix: &IpPrefix) -> Option<&V> {
+        self.trie(prefix.address).get(BitSlice::new(&prefix.octets(), prefix.length as usize))
+    }
+
+    /// Mutable getter of the value associated to the given prefix (exact match).
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the prefix to search for
+    ///
+    /// # Returns:
+    ///
+    /// the mutable value of the prefix or none if the prefix is not stored
+    pub fn get_mut(&mut self, prefix: &IpPrefix) -> Option<&mut V> {
+        self.trie_mut(prefix.address).get_mut(BitSlice::new(&prefix.octets(), prefix.length as usize))
+    }
+
+    /// Removes the given prefix from the table.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the prefix to remove
+    ///
+    /// # Returns:
+    ///
+    /// the value of the removed prefix or none if the prefix was not stored
+    pub fn remove(&mut self, prefix: &IpPrefix) -> Option<V> {
+        self.trie_mut(prefix.address).remove(BitSlice::new(&prefix.octets(), prefix.length as usize))
+    }
+
+    /// Indicates if the given prefix has been inserted into the table.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the prefix to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if the prefix has been inserted, False if it has not
+    pub fn contains_key(&self, prefix: &IpPrefix) -> bool {
+        self.trie(prefix.address).contains_key(BitSlice::new(&prefix.octets(), prefix.length as usize))
+    }
+
+    /// Searches for the longest stored prefix containing the given address.
+    ///
+    /// # Arguments:
+    ///
+    /// `address` - the address to route
+    ///
+    /// # Returns:
+    ///
+    /// the longest prefix containing the address with its value, or none if no prefix contains the address
+    pub fn longest_match(&self, address: IpAddr) -> Option<(IpPrefix, &V)> {
+
+        /* the address is matched from its octets, without any allocation */
+
+        let (octets, length) = address_octets(address);
+
+        self.trie(address)
+            .longest_prefix(BitSlice::new(&octets, length as usize))
+            .map(|(bits, value)| (IpPrefix::masked(address, bits.len() as u8), value))
+    }
+
+    /// Counts the prefixes stored into the table.
+    ///
+    /// # Returns:
+    ///
+    /// the amount of stored prefixes
+    pub fn len(&self) -> usize {
+        self.v4.len() + self.v6.len()
+    }
+
+    /// Indicates if the table contains no prefix.
+    ///
+    /// # Returns:
+    ///
+    /// true if no prefix is stored
+    pub fn is_empty(&self) -> bool {
+        self.v4.is_empty() && self.v6.is_empty()
+    }
+
+    /// Removes all the prefixes and values from the table.
+    pub fn clear(&mut self) {
+        self.v4.clear();
+        self.v6.clear();
+    }
+
+    /// Creates an iterator over all the prefixes and values: the IPv4 prefixes first,
+    /// then the IPv6 prefixes, each prefix followed by the prefixes it contains.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the prefixes and values
+    pub fn iter(&self) -> IpPrefixIter<'_, V> {
+
+        IpPrefixIter {
+            v4: self.v4.iter(),
+            v6: self.v6.iter(),
+        }
+    }
+
+    /// Getter of the trie storing the prefixes of the version of the given address.
+    ///
+    /// # Args:
+    ///
+    /// `address` - the address giving the version
+    ///
+    /// # Returns:
+    ///
+    /// the IPv4 or the IPv6 trie
+    fn trie(&self, address: IpAddr) -> &BitTrieMap<V> {
+
+        match address {
+            IpAddr::V4(_) => &self.v4,
+            IpAddr::V6(_) => &self.v6,
+        }
+    }
+
+    /// Mutable getter of the trie storing the prefixes of the version of the given address.
+    ///
+    /// # Args:
+    ///
+    /// `address` - the address giving the version
+    ///
+    /// # Returns:
+    ///
+    /// the mutable IPv4 or IPv6 trie
+    fn trie_mut(&mut self, address: IpAddr) -> &mut BitTrieMap<V> {
+
+        match address {
+            IpAddr::V4(_) => &mut self.v4,
+            IpAddr::V6(_) => &mut self.v6,
+        }
+    }
+}
+
+impl<'a, V> Iterator for IpPrefixIter<'a, V> {
+
+    type Item = (IpPrefix, &'a V);
+
+    fn next(&mut self) -> Option<(IpPrefix, &'a V)> {
+
+        if let Some((bits, value)) = self.v4.next() {
+            return Some((IpPrefix::from_bits(true, &bits), value));
+        }
+
+        self.v6
+            .next()
+            .map(|(bits, value)| (IpPrefix::from_bits(false, &bits), value))
+    }
+}
+
+impl<V> Default for IpPrefixTable<V> {
+
+    fn default() -> IpPrefixTable<V> {
+        IpPrefixTable::new()
+    }
+}
+
+impl<V> FromIterator<(IpPrefix, V)> for IpPrefixTable<V> {
+
+    fn from_iter<I: IntoIterator<Item = (IpPrefix, V)>>(iter: I) -> IpPrefixTable<V> {
+
+        let mut table = IpPrefixTable::new();
+        table.extend(iter);
+        table
+    }
+}
+
+impl<V> Extend<(IpPrefix, V)> for IpPrefixTable<V> {
+
+    fn extend<I: IntoIterator<Item = (IpPrefix, V)>>(&mut self, iter: I) {
+        for (prefix, value) in iter {
+            self.insert(prefix, value);
+        }
+    }
+}
+
+impl<'a, V> IntoIterator for &'a IpPrefixTable<V> {
+
+    type Item = (IpPrefix, &'a V);
+    type IntoIter = IpPrefixIter<'a, V>;
+
+    fn into_iter(self) -> IpPrefixIter<'a, V> {
+        self.iter()
+    }
+}
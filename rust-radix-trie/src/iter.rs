@@ -0,0 +1,263 @@
+use std::ops::Bound;
+
+use label::Label;
+use rt::RadixTrieMap;
+
+/// Iterator over the keys and values of a radix trie map, in lexicographic order of the keys.
+/// The nodes are browsed depth first: as the children of every node are sorted,
+/// no sort is required during the browsing. The keys can be limited to a range:
+/// the nodes whose keys are all out of the range are not browsed.
+pub struct Iter<'a, V: 'a, L: 'a = String> {
+    nodes: Vec<(&'a RadixTrieMap<V, L>, usize)>,
+    key: L,
+    skipped: usize,
+    start: Bound<L>,
+    end: Bound<L>,
+}
+
+/// Iterator over the keys of a radix trie map, in lexicographic order.
+pub struct Keys<'a, V: 'a, L: 'a = String> {
+    iter: Iter<'a, V, L>,
+}
+
+/// Iterator over the values of a radix trie map, in lexicographic order of their keys.
+/// The nodes are browsed depth first like the keys iterator, but the keys are not built.
+pub struct Values<'a, V: 'a, L: 'a = String> {
+    nodes: Vec<&'a RadixTrieMap<V, L>>,
+}
+
+impl<'a, V, L: Label> Iter<'a, V, L> {
+
+    /// Creates an iterator over all the keys stored from the given node.
+    ///
+    /// # Arguments:
+    ///
+    /// `node` - the node to start the iteration from
+    /// `prefix` - the characters of all the parents of the node
+    ///
+    /// # Returns:
+    ///
+    /// new iterator
+    pub(crate) fn new(node: &'a RadixTrieMap<V, L>, prefix: L::Slice<'_>) -> Iter<'a, V, L> {
+
+        Iter {
+            nodes: vec![(node, L::length(prefix))],
+            key: L::from_slice(prefix),
+            skipped: 0,
+            start: Bound::Unbounded,
+            end: Bound::Unbounded,
+        }
+    }
+
+    /// Creates an iterator over no key at all.
+    ///
+    /// # Returns:
+    ///
+    /// new empty iterator
+    pub(crate) fn empty() -> Iter<'a, V, L> {
+
+        Iter {
+            nodes: Vec::new(),
+            key: L::default(),
+            skipped: 0,
+            start: Bound::Unbounded,
+            end: Bound::Unbounded,
+        }
+    }
+
+    /// Limits the returned keys to the given range.
+    ///
+    /// # Arguments:
+    ///
+    /// `start` - the lower bound of the keys
+    /// `end` - the upper bound of the keys
+    ///
+    /// # Returns:
+    ///
+    /// the iterator returning keys of the range
+    pub(crate) fn with_bounds(mut self, start: Bound<L>, end: Bound<L>) -> Iter<'a, V, L> {
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    /// Indicates if the current key is before the lower bound of the range.
+    ///
+    /// # Returns:
+    ///
+    /// true if the current key is too small to be returned
+    fn before_start(&self) -> bool {
+
+        match self.start {
+            Bound::Included(ref start) => self.key < *start,
+            Bound::Excluded(ref start) => self.key <= *start,
+            Bound::Unbounded => false,
+        }
+    }
+
+    /// Indicates if the keys of the current node subtree are all before the lower bound of
+    /// the range. Subtree keys all start with the current key, so this is the case if the
+    /// current key is before the lower bound and is not its beginning.
+    ///
+    /// # Returns:
+    ///
+    /// true if the subtree of the current node can be skipped
+    fn subtree_before_start(&self) -> bool {
+
+        match self.start {
+            Bound::Included(ref start) | Bound::Excluded(ref start) => {
+                self.key < *start && L::common_length(start.as_slice(), self.key.as_slice()) < L::length(self.key.as_slice())
+            },
+            Bound::Unbounded => false,
+        }
+    }
+
+    /// Indicates if the current key is after the upper bound of the range.
+    /// As subtree keys all start with the current key, they are all after the upper bound too.
+    ///
+    /// # Returns:
+    ///
+    /// true if the current key and its subtree keys are too big to be returned
+    fn after_end(&self) -> bool {
+
+        match self.end {
+            Bound::Included(ref end) => self.key > *end,
+            Bound::Excluded(ref end) => self.key >= *end,
+            Bound::Unbounded => false,
+        }
+    }
+
+    /// Removes the given amount of bytes from the beginning of every returned key;
+    /// all the browsed keys must start with the same characters of that length.
+    ///
+    /// # Arguments:
+    ///
+    /// `length` - the length of the common beginning of the keys to remove
+    ///
+    /// # Returns:
+    ///
+    /// the iterator returning shortened keys
+    pub(crate) fn without_prefix(mut self, length: usize) -> Iter<'a, V, L> {
+        self.skipped = length;
+        self
+    }
+}
+
+impl<'a, V, L: Label> Iterator for Iter<'a, V, L> {
+
+    type Item = (L, &'a V);
+
+    fn next(&mut self) -> Option<(L, &'a V)> {
+
+        while let Some((node, length)) = self.nodes.pop() {
+
+            /* the key is shared by all the browsed nodes,
+               so the characters of the previous branch are removed first */
+
+            self.key.truncate(length);
+            self.key.push_slice(node.get_characters());
+
+            if self.after_end() {
+
+                /* the remaining nodes have bigger keys,
+                   so the iteration is over */
+
+                self.nodes.clear();
+                return None;
+            }
+
+            if self.subtree_before_start() {
+                continue;
+            }
+
+            let length = L::length(self.key.as_slice());
+
+            self.nodes.extend(
+                node.get_children()
+                    .iter()
+                    .rev()
+                    .map(|child| (child, length))
+            );
+
+            if self.before_start() {
+                continue;
+            }
+
+            if let Some(value) = node.get_value() {
+                let (_, key) = L::split_at(self.key.as_slice(), self.skipped);
+                return Some((L::from_slice(key), value));
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, V, L: Label> Keys<'a, V, L> {
+
+    /// Creates an iterator over the keys browsed by the given iterator.
+    ///
+    /// # Arguments:
+    ///
+    /// `iter` - the iterator over keys and values
+    ///
+    /// # Returns:
+    ///
+    /// new iterator
+    pub(crate) fn new(iter: Iter<'a, V, L>) -> Keys<'a, V, L> {
+        Keys { iter }
+    }
+}
+
+impl<'a, V, L: Label> Iterator for Keys<'a, V, L> {
+
+    type Item = L;
+
+    fn next(&mut self) -> Option<L> {
+        self.iter.next().map(|(key, _)| key)
+    }
+}
+
+impl<'a, V, L: Label> Values<'a, V, L> {
+
+    /// Creates an iterator over all the values stored from the given node.
+    ///
+    /// # Arguments:
+    ///
+    /// `node` - the node to start the iteration from
+    ///
+    /// # Returns:
+    ///
+    /// new iterator
+    pub(crate) fn new(node: &'a RadixTrieMap<V, L>) -> Values<'a, V, L> {
+        Values { nodes: vec![node] }
+    }
+
+    /// Creates an iterator over no value at all.
+    ///
+    /// # Returns:
+    ///
+    /// new empty iterator
+    pub(crate) fn empty() -> Values<'a, V, L> {
+        Values { nodes: Vec::new() }
+    }
+}
+
+impl<'a, V, L: Label> Iterator for Values<'a, V, L> {
+
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<&'a V> {
+
+        while let Some(node) = self.nodes.pop() {
+
+            self.nodes.extend(node.get_children().iter().rev());
+
+            if let Some(value) = node.get_value() {
+                return Some(value);
+            }
+        }
+
+        None
+    }
+}
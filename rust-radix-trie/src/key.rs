@@ -0,0 +1,283 @@
+use std::ffi::{
+    OsStr,
+    OsString,
+};
+use std::mem;
+use std::net::{
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr,
+};
+#[cfg(unix)]
+use std::os::unix::ffi::{
+    OsStrExt,
+    OsStringExt,
+};
+use std::path::PathBuf;
+
+/// A type which can be used as a key of a radix trie. The key is encoded into a sequence
+/// of bytes: the lexicographic order of the encoded keys is the order of the keys,
+/// and the encoded keys sharing a prefix are stored into the same subtree.
+pub trait TrieKey: Sized {
+
+    /// Encodes the key into bytes.
+    ///
+    /// # Returns:
+    ///
+    /// the encoded key
+    fn encode(&self) -> Vec<u8>;
+
+    /// Decodes a key from the bytes created by `encode`.
+    ///
+    /// # Arguments:
+    ///
+    /// `bytes` - the encoded key
+    ///
+    /// # Returns:
+    ///
+    /// the decoded key
+    fn decode(bytes: &[u8]) -> Self;
+}
+
+/// Separator ending every path component: it is smaller than any other byte,
+/// so the paths are sorted component by component.
+const PATH_SEPARATOR: u8 = 0;
+
+/// Escape byte of the null and escape bytes inside a path component, followed by the escaped
+/// byte plus one: the escaped components keep the order of the raw components.
+const PATH_ESCAPE: u8 = 1;
+
+impl TrieKey for String {
+
+    fn encode(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn decode(bytes: &[u8]) -> String {
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+impl TrieKey for Vec<u8> {
+
+    fn encode(&self) -> Vec<u8> {
+        self.clone()
+    }
+
+    fn decode(bytes: &[u8]) -> Vec<u8> {
+        bytes.to_vec()
+    }
+}
+
+impl TrieKey for Box<[u8]> {
+
+    fn encode(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+
+    fn decode(bytes: &[u8]) -> Box<[u8]> {
+        bytes.into()
+    }
+}
+
+/// Implements the key trait for unsigned integers, encoded in big-endian
+/// so the most significant bytes are compared first.
+macro_rules! unsigned_trie_key {
+    ($($integer: ty),*) => {
+        $(
+            impl TrieKey for $integer {
+
+                fn encode(&self) -> Vec<u8> {
+                    self.to_be_bytes().to_vec()
+                }
+
+                fn decode(bytes: &[u8]) -> $integer {
+
+                    let mut array = [0; std::mem::size_of::<$integer>()];
+                    array.copy_from_slice(bytes);
+
+                    <$integer>::from_be_bytes(array)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements the key trait for signed integers, encoded in big-endian with
+/// the sign bit flipped, so the negative integers are before the positive ones.
+macro_rules! signed_trie_key {
+    ($($integer: ty => $unsigned: ty),*) => {
+        $(
+            impl TrieKey for $integer {
+
+                fn encode(&self) -> Vec<u8> {
+                    ((*self as $unsigned) ^ (1 << (<$unsigned>::BITS - 1))).encode()
+                }
+
+                fn decode(bytes: &[u8]) -> $integer {
+                    (<$unsigned>::decode(bytes) ^ (1 << (<$unsigned>::BITS - 1))) as $integer
+                }
+            }
+        )*
+    };
+}
+
+unsigned_trie_key!(u8, u16, u32, u64, u128, usize);
+signed_trie_key!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+impl TrieKey for PathBuf {
+
+    /// Encodes the raw bytes of every component of the path, each one followed by a null byte,
+    /// so a path is a prefix of another path only if its components are the first components
+    /// of the other path. The null and escape bytes inside a component are escaped.
+    fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = Vec::new();
+
+        for component in self.components() {
+
+            for &byte in component_bytes(component.as_os_str()).iter() {
+
+                if byte <= PATH_ESCAPE {
+                    bytes.push(PATH_ESCAPE);
+                    bytes.push(byte + 1);
+                } else {
+                    bytes.push(byte);
+                }
+            }
+
+            bytes.push(PATH_SEPARATOR);
+        }
+
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> PathBuf {
+
+        let mut path = PathBuf::new();
+        let mut component = Vec::new();
+        let mut escaped = false;
+
+        for &byte in bytes {
+
+            if escaped {
+                component.push(byte - 1);
+                escaped = false;
+            } else if byte == PATH_ESCAPE {
+                escaped = true;
+            } else if byte == PATH_SEPARATOR {
+                path.push(component_from_bytes(mem::take(&mut component)));
+            } else {
+                component.push(byte);
+            }
+        }
+
+        path
+    }
+}
+
+/// Getter of the raw bytes of a path component.
+///
+/// # Args:
+///
+/// `component` - the path component
+///
+/// # Returns:
+///
+/// the bytes of the component
+#[cfg(unix)]
+fn component_bytes(component: &OsStr) -> &[u8] {
+    component.as_bytes()
+}
+
+/// Getter of the bytes of a path component, in the platform encoding of the strings.
+///
+/// # Args:
+///
+/// `component` - the path component
+///
+/// # Returns:
+///
+/// the bytes of the component
+#[cfg(not(unix))]
+fn component_bytes(component: &OsStr) -> &[u8] {
+    component.as_encoded_bytes()
+}
+
+/// Creates a path component from its raw bytes.
+///
+/// # Args:
+///
+/// `bytes` - the bytes of the component
+///
+/// # Returns:
+///
+/// the path component
+#[cfg(unix)]
+fn component_from_bytes(bytes: Vec<u8>) -> OsString {
+    OsString::from_vec(bytes)
+}
+
+/// Creates a path component from its bytes, in the platform encoding of the strings.
+///
+/// # Args:
+///
+/// `bytes` - the bytes of the component
+///
+/// # Returns:
+///
+/// the path component
+#[cfg(not(unix))]
+fn component_from_bytes(bytes: Vec<u8>) -> OsString {
+    match String::from_utf8(bytes) {
+        Ok(component) => OsString::from(component),
+        Err(error) => OsString::from(String::from_utf8_lossy(error.as_bytes()).into_owned()),
+    }
+}
+
+impl TrieKey for Ipv4Addr {
+
+    fn encode(&self) -> Vec<u8> {
+        self.octets().to_vec()
+    }
+
+    fn decode(bytes: &[u8]) -> Ipv4Addr {
+        Ipv4Addr::from(u32::decode(bytes))
+    }
+}
+
+impl TrieKey for Ipv6Addr {
+
+    fn encode(&self) -> Vec<u8> {
+        self.octets().to_vec()
+    }
+
+    fn decode(bytes: &[u8]) -> Ipv6Addr {
+        Ipv6Addr::from(u128::decode(bytes))
+    }
+}
+
+impl TrieKey for IpAddr {
+
+    /// Encodes the version of the address followed by its octets,
+    /// so the IPv4 addresses are before the IPv6 addresses.
+    fn encode(&self) -> Vec<u8> {
+
+        let (version, octets) = match *self {
+            IpAddr::V4(address) => (4, address.encode()),
+            IpAddr::V6(address) => (6, address.encode()),
+        };
+
+        let mut bytes = vec![version];
+        bytes.extend(octets);
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> IpAddr {
+
+        match bytes[0] {
+            4 => IpAddr::V4(Ipv4Addr::decode(&bytes[1..])),
+            _ => IpAddr::V6(Ipv6Addr::decode(&bytes[1..])),
+        }
+    }
+}
@@ -0,0 +1,196 @@
+/// The characters stored into the radix trie nodes, as the label of the edge from the parent node.
+/// Labels are either strings, split on characters boundaries, byte vectors, split anywhere,
+/// or bit vectors, split between any bits. The nodes are split and merged the same way
+/// for every kind of label. The indices into the labels count the bytes of the strings
+/// and of the byte vectors, and the bits of the bit vectors.
+pub trait Label: Clone + Default + Ord {
+
+    /// The borrowed form of the label, used for the keys given to the trie.
+    type Slice<'a>: Copy + Ord where Self: 'a;
+
+    /// The unit of the labels: the children of a node are sorted by their first unit,
+    /// which is different for every child.
+    type Unit: Ord;
+
+    /// Copies the given slice into a label.
+    ///
+    /// # Arguments:
+    ///
+    /// `slice` - the slice to copy
+    ///
+    /// # Returns:
+    ///
+    /// new label
+    fn from_slice(slice: Self::Slice<'_>) -> Self;
+
+    /// Borrows the label as a slice.
+    ///
+    /// # Returns:
+    ///
+    /// the slice of the whole label
+    fn as_slice(&self) -> Self::Slice<'_>;
+
+    /// Getter of the first unit of the given slice.
+    ///
+    /// # Arguments:
+    ///
+    /// `slice` - the slice to read
+    ///
+    /// # Returns:
+    ///
+    /// the first unit, or none if the slice is empty
+    fn first_unit(slice: Self::Slice<'_>) -> Option<Self::Unit>;
+
+    /// Divides the given slice into two at the given index.
+    ///
+    /// # Arguments:
+    ///
+    /// `slice` - the slice to divide
+    /// `index` - the index where to divide the slice, on a unit boundary
+    ///
+    /// # Returns:
+    ///
+    /// the slice before the index and the slice after the index
+    fn split_at<'a>(slice: Self::Slice<'a>, index: usize) -> (Self::Slice<'a>, Self::Slice<'a>) where Self: 'a;
+
+    /// Computes the length of the common beginning of two slices, on a unit boundary.
+    ///
+    /// # Arguments:
+    ///
+    /// `first` - the first slice
+    /// `second` - the second slice
+    ///
+    /// # Returns:
+    ///
+    /// the index of the first unit which differs between the slices, or the length
+    /// of the shortest slice if it is the beginning of the other one
+    fn common_length(first: Self::Slice<'_>, second: Self::Slice<'_>) -> usize;
+
+    /// Appends the given slice to the label.
+    ///
+    /// # Arguments:
+    ///
+    /// `slice` - the slice to append
+    fn push_slice(&mut self, slice: Self::Slice<'_>);
+
+    /// Shortens the label to the given length.
+    ///
+    /// # Arguments:
+    ///
+    /// `length` - the new length of the label, on a unit boundary
+    fn truncate(&mut self, length: usize);
+
+    /// Getter of the length of the given slice.
+    ///
+    /// # Arguments:
+    ///
+    /// `slice` - the slice to measure
+    ///
+    /// # Returns:
+    ///
+    /// the amount of bytes (or bits) of the slice
+    fn length(slice: Self::Slice<'_>) -> usize;
+}
+
+/// Computes the length of the common beginning of two byte slices.
+///
+/// # Args:
+///
+/// `first` - the first bytes
+/// `second` - the second bytes
+///
+/// # Returns:
+///
+/// the index of the first different byte, or the length of the shortest slice
+fn common_bytes_length(first: &[u8], second: &[u8]) -> usize {
+
+    first.iter()
+        .zip(second)
+        .position(|(first, second)| first != second)
+        .unwrap_or_else(|| first.len().min(second.len()))
+}
+
+impl Label for String {
+
+    type Slice<'a> = &'a str;
+    type Unit = char;
+
+    fn from_slice(slice: &str) -> String {
+        slice.to_string()
+    }
+
+    fn as_slice(&self) -> &str {
+        self
+    }
+
+    fn first_unit(slice: &str) -> Option<char> {
+        slice.chars().next()
+    }
+
+    fn split_at<'a>(slice: &'a str, index: usize) -> (&'a str, &'a str) where Self: 'a {
+        slice.split_at(index)
+    }
+
+    fn common_length(first: &str, second: &str) -> usize {
+
+        /* two different characters may share their first bytes,
+           so the index goes back to the beginning of the character */
+
+        let mut index = common_bytes_length(first.as_bytes(), second.as_bytes());
+        while !first.is_char_boundary(index) {
+            index -= 1;
+        }
+
+        index
+    }
+
+    fn push_slice(&mut self, slice: &str) {
+        self.push_str(slice);
+    }
+
+    fn truncate(&mut self, length: usize) {
+        String::truncate(self, length);
+    }
+
+    fn length(slice: &str) -> usize {
+        slice.len()
+    }
+}
+
+impl Label for Vec<u8> {
+
+    type Slice<'a> = &'a [u8];
+    type Unit = u8;
+
+    fn from_slice(slice: &[u8]) -> Vec<u8> {
+        slice.to_vec()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn first_unit(slice: &[u8]) -> Option<u8> {
+        slice.first().cloned()
+    }
+
+    fn split_at<'a>(slice: &'a [u8], index: usize) -> (&'a [u8], &'a [u8]) where Self: 'a {
+        slice.split_at(index)
+    }
+
+    fn common_length(first: &[u8], second: &[u8]) -> usize {
+        common_bytes_length(first, second)
+    }
+
+    fn push_slice(&mut self, slice: &[u8]) {
+        self.extend_from_slice(slice);
+    }
+
+    fn truncate(&mut self, length: usize) {
+        Vec::truncate(self, length);
+    }
+
+    fn length(slice: &[u8]) -> usize {
+        slice.len()
+    }
+}
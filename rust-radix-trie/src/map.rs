@@ -0,0 +1,233 @@
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+
+use bytes::ByteTrieMap;
+use iter::Iter;
+use key::TrieKey;
+
+/// A radix trie map with keys of any type implementing the key trait.
+/// The keys are stored encoded into a byte radix trie map.
+#[derive(Clone)]
+pub struct TrieMap<K, V> {
+    map: ByteTrieMap<V>,
+    keys: PhantomData<K>,
+}
+
+/// Iterator over the keys and values of a trie map, in the order of the encoded keys.
+pub struct TrieMapIter<'a, K, V: 'a> {
+    iter: Iter<'a, V, Vec<u8>>,
+    keys: PhantomData<K>,
+}
+
+impl<K: TrieKey, V> TrieMap<K, V> {
+
+    /// Creates a new empty trie map.
+    ///
+    /// # Returns:
+    ///
+    /// new trie map
+    pub fn new() -> TrieMap<K, V> {
+
+        TrieMap {
+            map: ByteTrieMap::default(),
+            keys: PhantomData,
+        }
+    }
+
+    /// Inserts a key with its value into the trie map.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to store
+    /// `value` - the value to associate to the key
+    ///
+    /// # Returns:
+    ///
+    /// the previous value of the key if the key was already stored, none otherwise
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.map.insert(&key.encode(), value)
+    }
+
+    /// Getter of the value associated to the given key.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to search for
+    ///
+    /// # Returns:
+    ///
+    /// the value of the key or none if the key is not stored
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.map.get(&key.encode())
+    }
+
+    /// Mutable getter of the value associated to the given key.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to search for
+    ///
+    /// # Returns:
+    ///
+    /// the mutable value of the key or none if the key is not stored
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.map.get_mut(&key.encode())
+    }
+
+    /// Removes the given key from the trie map.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to remove
+    ///
+    /// # Returns:
+    ///
+    /// the value of the removed key or none if the key was not stored
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.map.remove(&key.encode())
+    }
+
+    /// Indicates if the given key has been inserted into the trie map.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if the key has been inserted, False if it has not
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.map.contains_key(&key.encode())
+    }
+
+    /// Counts the keys stored into the trie map.
+    ///
+    /// # Returns:
+    ///
+    /// the amount of stored keys
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Indicates if the trie map contains no key.
+    ///
+    /// # Returns:
+    ///
+    /// true if no key is stored
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Removes all the keys and values from the trie map.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Creates an iterator over all the keys and values, in the order of the encoded keys.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the keys and values
+    pub fn iter(&self) -> TrieMapIter<'_, K, V> {
+        TrieMapIter::new(self.map.iter())
+    }
+
+    /// Creates an iterator over the keys whose encoding starts with the encoding of the given prefix,
+    /// with their values (for instance a directory and the paths inside it, or the byte strings
+    /// starting with some bytes).
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the beginning of the keys to iterate over
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the keys and values
+    pub fn iter_prefix(&self, prefix: &K) -> TrieMapIter<'_, K, V> {
+        TrieMapIter::new(self.map.iter_prefix(&prefix.encode()))
+    }
+
+    /// Searches for the longest stored key whose encoding is a prefix of the encoding of the given query.
+    ///
+    /// # Arguments:
+    ///
+    /// `query` - the key to match
+    ///
+    /// # Returns:
+    ///
+    /// the longest key with its value, or none if no key is a prefix of the query
+    pub fn longest_prefix(&self, query: &K) -> Option<(K, &V)> {
+
+        self.map
+            .longest_prefix(&query.encode())
+            .map(|(key, value)| (K::decode(key), value))
+    }
+}
+
+impl<'a, K, V> TrieMapIter<'a, K, V> {
+
+    /// Creates an iterator decoding the keys browsed by the given iterator.
+    ///
+    /// # Arguments:
+    ///
+    /// `iter` - the iterator over the encoded keys and values
+    ///
+    /// # Returns:
+    ///
+    /// new iterator
+    fn new(iter: Iter<'a, V, Vec<u8>>) -> TrieMapIter<'a, K, V> {
+
+        TrieMapIter {
+            iter,
+            keys: PhantomData,
+        }
+    }
+}
+
+impl<'a, K: TrieKey, V> Iterator for TrieMapIter<'a, K, V> {
+
+    type Item = (K, &'a V);
+
+    fn next(&mut self) -> Option<(K, &'a V)> {
+
+        self.iter
+            .next()
+            .map(|(key, value)| (K::decode(&key), value))
+    }
+}
+
+impl<K: TrieKey, V> Default for TrieMap<K, V> {
+
+    fn default() -> TrieMap<K, V> {
+        TrieMap::new()
+    }
+}
+
+impl<K: TrieKey, V> FromIterator<(K, V)> for TrieMap<K, V> {
+
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> TrieMap<K, V> {
+
+        let mut map = TrieMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: TrieKey, V> Extend<(K, V)> for TrieMap<K, V> {
+
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K: TrieKey, V> IntoIterator for &'a TrieMap<K, V> {
+
+    type Item = (K, &'a V);
+    type IntoIter = TrieMapIter<'a, K, V>;
+
+    fn into_iter(self) -> TrieMapIter<'a, K, V> {
+        self.iter()
+    }
+}
@@ -0,0 +1,523 @@
+use std::error::Error;
+use std::fmt;
+
+use automaton::Automaton;
+
+/// Simple regular expression matching whole keys. The supported syntax is:
+/// literal characters, `.` (any character), classes like `[a-z_]` or `[^0-9]`,
+/// groups `(...)`, alternations `a|b`, repetitions `*`, `+` and `?`,
+/// and `\` to escape the special characters.
+///
+/// The expression is compiled into a nondeterministic automaton; the state
+/// of the matcher is the set of automaton states reached after reading the
+/// characters of a key, so the matcher behaves as the equivalent deterministic automaton.
+#[derive(Clone, Debug)]
+pub struct Regex {
+    states: Vec<State>,
+    start: usize,
+    alive: Vec<bool>,
+}
+
+/// Error returned when a regular expression cannot be compiled.
+/// Positions are indexes of characters into the expression.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegexError {
+
+    /// A group is opened and never closed, or closed without being opened.
+    UnbalancedParenthesis(usize),
+
+    /// A class is opened and never closed.
+    UnclosedClass(usize),
+
+    /// A repetition is not preceded by anything to repeat.
+    NothingToRepeat(usize),
+
+    /// The expression ends with an escape character.
+    TrailingEscape,
+}
+
+/// Set of characters: the characters of the ranges, or all the others if the set is negated.
+#[derive(Clone, Debug)]
+struct CharacterSet {
+    ranges: Vec<(char, char)>,
+    negated: bool,
+}
+
+/// Parsed regular expression.
+enum Expression {
+    Characters(CharacterSet),
+    Concatenation(Vec<Expression>),
+    Alternation(Vec<Expression>),
+    ZeroOrMore(Box<Expression>),
+    OneOrMore(Box<Expression>),
+    ZeroOrOne(Box<Expression>),
+}
+
+/// State of the nondeterministic automaton, with the indexes of the next states.
+#[derive(Clone, Debug)]
+enum State {
+    Match,
+    Read(CharacterSet, usize),
+    Split(Vec<usize>),
+}
+
+/// Recursive descent parser of regular expressions.
+struct Parser {
+    characters: Vec<char>,
+    position: usize,
+}
+
+impl fmt::Display for RegexError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+
+        match *self {
+            RegexError::UnbalancedParenthesis(position) => {
+                write!(formatter, "unbalanced parenthesis at position {}", position)
+            },
+            RegexError::UnclosedClass(position) => {
+                write!(formatter, "unclosed class at position {}", position)
+            },
+            RegexError::NothingToRepeat(position) => {
+                write!(formatter, "nothing to repeat at position {}", position)
+            },
+            RegexError::TrailingEscape => {
+                write!(formatter, "trailing escape character")
+            },
+        }
+    }
+}
+
+impl Error for RegexError {}
+
+impl CharacterSet {
+
+    /// Creates a set of a single character.
+    ///
+    /// # Args:
+    ///
+    /// `character` - the character of the set
+    ///
+    /// # Returns:
+    ///
+    /// new set
+    fn single(character: char) -> CharacterSet {
+
+        CharacterSet {
+            ranges: vec![(character, character)],
+            negated: false,
+        }
+    }
+
+    /// Indicates if the given character belongs to the set.
+    ///
+    /// # Args:
+    ///
+    /// `character` - the character to check
+    ///
+    /// # Returns:
+    ///
+    /// true if the character belongs to the set
+    fn contains(&self, character: char) -> bool {
+
+        let in_ranges = self.ranges
+            .iter()
+            .any(|&(first, last)| first <= character && character <= last);
+
+        in_ranges != self.negated
+    }
+}
+
+impl Parser {
+
+    /// Parses an alternation of concatenations, until the end of the expression or a closing parenthesis.
+    ///
+    /// # Returns:
+    ///
+    /// the parsed expression
+    fn parse_alternation(&mut self) -> Result<Expression, RegexError> {
+
+        let mut alternatives = vec![self.parse_concatenation()?];
+
+        while self.peek() == Some('|') {
+            self.position += 1;
+            alternatives.push(self.parse_concatenation()?);
+        }
+
+        if alternatives.len() == 1 {
+            return Ok(alternatives.pop().unwrap());
+        }
+
+        Ok(Expression::Alternation(alternatives))
+    }
+
+    /// Parses a sequence of repeated atoms, until an alternation, a closing parenthesis or the end.
+    ///
+    /// # Returns:
+    ///
+    /// the parsed expression
+    fn parse_concatenation(&mut self) -> Result<Expression, RegexError> {
+
+        let mut expressions = Vec::new();
+
+        while let Some(character) = self.peek() {
+
+            if character == '|' || character == ')' {
+                break;
+            }
+
+            let mut expression = self.parse_atom()?;
+
+            while let Some(repetition) = self.peek() {
+
+                expression = match repetition {
+                    '*' => Expression::ZeroOrMore(Box::new(expression)),
+                    '+' => Expression::OneOrMore(Box::new(expression)),
+                    '?' => Expression::ZeroOrOne(Box::new(expression)),
+                    _ => break,
+                };
+
+                self.position += 1;
+            }
+
+            expressions.push(expression);
+        }
+
+        Ok(Expression::Concatenation(expressions))
+    }
+
+    /// Parses a single character, a class or a group.
+    ///
+    /// # Returns:
+    ///
+    /// the parsed expression
+    fn parse_atom(&mut self) -> Result<Expression, RegexError> {
+
+        let position = self.position;
+        let character = self.next().unwrap();
+
+        match character {
+            '(' => {
+
+                let expression = self.parse_alternation()?;
+
+                if self.next() != Some(')') {
+                    return Err(RegexError::UnbalancedParenthesis(position));
+                }
+
+                Ok(expression)
+            },
+            '[' => self.parse_class(position),
+            '.' => Ok(Expression::Characters(CharacterSet { ranges: Vec::new(), negated: true })),
+            '*' | '+' | '?' => Err(RegexError::NothingToRepeat(position)),
+            '\\' => self.parse_escaped().map(|character| Expression::Characters(CharacterSet::single(character))),
+            _ => Ok(Expression::Characters(CharacterSet::single(character))),
+        }
+    }
+
+    /// Parses the content of a class, after its opening bracket.
+    ///
+    /// # Args:
+    ///
+    /// `position` - the position of the opening bracket
+    ///
+    /// # Returns:
+    ///
+    /// the parsed class
+    fn parse_class(&mut self, position: usize) -> Result<Expression, RegexError> {
+
+        let negated = self.peek() == Some('^');
+
+        if negated {
+            self.position += 1;
+        }
+
+        let mut ranges = Vec::new();
+
+        loop {
+
+            let first = match self.next() {
+                Some(']') => break,
+                Some('\\') => self.parse_escaped()?,
+                Some(character) => character,
+                None => return Err(RegexError::UnclosedClass(position)),
+            };
+
+            /* a dash before the closing bracket is a simple character */
+
+            let is_range = self.peek() == Some('-') &&
+                self.characters.get(self.position + 1).is_some_and(|&next| next != ']');
+
+            if !is_range {
+                ranges.push((first, first));
+                continue;
+            }
+
+            self.position += 1;
+
+            let last = match self.next() {
+                Some('\\') => self.parse_escaped()?,
+                Some(character) => character,
+                None => return Err(RegexError::UnclosedClass(position)),
+            };
+
+            ranges.push((first, last));
+        }
+
+        Ok(Expression::Characters(CharacterSet { ranges, negated }))
+    }
+
+    /// Parses the character following an escape character.
+    ///
+    /// # Returns:
+    ///
+    /// the escaped character
+    fn parse_escaped(&mut self) -> Result<char, RegexError> {
+        self.next().ok_or(RegexError::TrailingEscape)
+    }
+
+    /// Getter of the current character, if any.
+    ///
+    /// # Returns:
+    ///
+    /// the current character
+    fn peek(&self) -> Option<char> {
+        self.characters.get(self.position).cloned()
+    }
+
+    /// Moves to the next character.
+    ///
+    /// # Returns:
+    ///
+    /// the current character, before moving
+    fn next(&mut self) -> Option<char> {
+
+        let character = self.peek();
+        self.position += 1;
+
+        character
+    }
+}
+
+impl Regex {
+
+    /// Compiles the given regular expression. The expression has to match whole keys,
+    /// so `ab` only matches the key "ab", and `ab.*` matches the keys starting with "ab".
+    ///
+    /// # Arguments:
+    ///
+    /// `expression` - the regular expression to compile
+    ///
+    /// # Returns:
+    ///
+    /// the compiled regular expression, or the error of the expression syntax
+    pub fn new(expression: &str) -> Result<Regex, RegexError> {
+
+        let mut parser = Parser {
+            characters: expression.chars().collect(),
+            position: 0,
+        };
+
+        let parsed = parser.parse_alternation()?;
+
+        if parser.position < parser.characters.len() {
+            return Err(RegexError::UnbalancedParenthesis(parser.position));
+        }
+
+        let mut states = vec![State::Match];
+        let start = compile(&parsed, 0, &mut states);
+
+        let alive = alive_states(&states);
+
+        Ok(Regex { states, start, alive })
+    }
+
+    /// Adds the given state and the states reachable from it without reading any character.
+    ///
+    /// # Args:
+    ///
+    /// `index` - the index of the state to add
+    /// `reached` - the reached states, indexed by state
+    fn add_state(&self, index: usize, reached: &mut Vec<bool>) {
+
+        if reached[index] {
+            return;
+        }
+
+        reached[index] = true;
+
+        if let State::Split(ref nexts) = self.states[index] {
+            for &next in nexts {
+                self.add_state(next, reached);
+            }
+        }
+    }
+
+    /// Converts the reached states flags into the sorted list of the reached states.
+    ///
+    /// # Args:
+    ///
+    /// `reached` - the reached states, indexed by state
+    ///
+    /// # Returns:
+    ///
+    /// the indexes of the reached states
+    fn reached_states(reached: Vec<bool>) -> Vec<usize> {
+
+        reached.into_iter()
+            .enumerate()
+            .filter(|&(_, is_reached)| is_reached)
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+impl Automaton for Regex {
+
+    type State = Vec<usize>;
+
+    fn start(&self) -> Vec<usize> {
+
+        let mut reached = vec![false; self.states.len()];
+        self.add_state(self.start, &mut reached);
+
+        Regex::reached_states(reached)
+    }
+
+    fn step(&self, state: &Vec<usize>, character: char) -> Vec<usize> {
+
+        let mut reached = vec![false; self.states.len()];
+
+        for &index in state {
+            if let State::Read(ref set, next) = self.states[index] {
+                if set.contains(character) {
+                    self.add_state(next, &mut reached);
+                }
+            }
+        }
+
+        Regex::reached_states(reached)
+    }
+
+    fn is_match(&self, state: &Vec<usize>) -> bool {
+
+        /* the match state is always the first state */
+
+        state.first() == Some(&0)
+    }
+
+    fn can_match(&self, state: &Vec<usize>) -> bool {
+        state.iter().any(|&index| self.alive[index])
+    }
+}
+
+/// Recursively adds the states of the given expression to the automaton. The states
+/// are created from the end of the expression, so every state knows its next states.
+///
+/// # Args:
+///
+/// `expression` - the expression to compile
+/// `next` - the index of the state following the expression
+/// `states` - the states of the automaton
+///
+/// # Returns:
+///
+/// the index of the first state of the expression
+fn compile(expression: &Expression, next: usize, states: &mut Vec<State>) -> usize {
+
+    match *expression {
+        Expression::Characters(ref set) => {
+            states.push(State::Read(set.clone(), next));
+            states.len() - 1
+        },
+        Expression::Concatenation(ref expressions) => {
+            expressions
+                .iter()
+                .rev()
+                .fold(next, |next, expression| compile(expression, next, states))
+        },
+        Expression::Alternation(ref expressions) => {
+
+            let starts = expressions
+                .iter()
+                .map(|expression| compile(expression, next, states))
+                .collect();
+
+            states.push(State::Split(starts));
+            states.len() - 1
+        },
+        Expression::ZeroOrMore(ref expression) => {
+
+            /* the loop state is created first, so the repeated expression can go back to it */
+
+            states.push(State::Split(Vec::new()));
+            let loop_index = states.len() - 1;
+
+            let start = compile(expression, loop_index, states);
+            states[loop_index] = State::Split(vec![start, next]);
+
+            loop_index
+        },
+        Expression::OneOrMore(ref expression) => {
+
+            states.push(State::Split(Vec::new()));
+            let loop_index = states.len() - 1;
+
+            let start = compile(expression, loop_index, states);
+            states[loop_index] = State::Split(vec![start, next]);
+
+            start
+        },
+        Expression::ZeroOrOne(ref expression) => {
+
+            let start = compile(expression, next, states);
+
+            states.push(State::Split(vec![start, next]));
+            states.len() - 1
+        },
+    }
+}
+
+/// Finds the states from which the match state can be reached.
+///
+/// # Args:
+///
+/// `states` - the states of the automaton
+///
+/// # Returns:
+///
+/// the alive states flags, indexed by state
+fn alive_states(states: &[State]) -> Vec<bool> {
+
+    let mut alive = vec![false; states.len()];
+    alive[0] = true;
+
+    /* the transitions are browsed again until no new alive state is found */
+
+    let mut changed = true;
+
+    while changed {
+
+        changed = false;
+
+        for (index, state) in states.iter().enumerate() {
+
+            if alive[index] {
+                continue;
+            }
+
+            let reaches_alive = match *state {
+                State::Match => true,
+                State::Read(ref set, next) => alive[next] && (set.negated || !set.ranges.is_empty()),
+                State::Split(ref nexts) => nexts.iter().any(|&next| alive[next]),
+            };
+
+            if reaches_alive {
+                alive[index] = true;
+                changed = true;
+            }
+        }
+    }
+
+    alive
+}
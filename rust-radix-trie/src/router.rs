@@ -0,0 +1,456 @@
+use std::error::Error;
+use std::fmt;
+
+use rt::RadixTrieMap;
+
+/// A router of HTTP paths: route patterns are stored into a tree of radix trie maps,
+/// and a request path is resolved into the handler of the matching route with
+/// the parameters extracted from the path.
+///
+/// A pattern starts with `/` and is made of static parts, parameters `:name` matching
+/// one non-empty path segment (up to the next `/`), and optionally ends with a catch-all
+/// `*name` matching the rest of the path. For instance, `/users/:id/posts/*rest` matches
+/// `/users/42/posts/2024/01`, with `id` = `42` and `rest` = `2024/01`.
+///
+/// Static parts take priority over parameters, which take priority over catch-alls:
+/// `/users/new` and `/users/:id` can both be inserted. Two patterns are conflicting if
+/// they use different parameters names at the same position, or if a parameter and
+/// a catch-all start at the same position.
+pub struct Router<H> {
+    root: RouteNode<H>,
+    count: usize,
+}
+
+/// The result of a resolved path: the handler of the matching route and the parameters of the path.
+pub struct RouteMatch<'r, 'p, H: 'r> {
+    pattern: &'r str,
+    handler: &'r H,
+    params: Vec<(&'r str, &'p str)>,
+}
+
+/// Error returned when a route cannot be inserted into a router.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RouteError {
+
+    /// The pattern does not start with `/`, has an unnamed or repeated parameter,
+    /// or has a catch-all which is not at the end.
+    InvalidPattern(String),
+
+    /// The pattern has already been inserted.
+    DuplicateRoute(String),
+
+    /// The pattern (first) conflicts with the pattern of an existing route (second).
+    ConflictingRoute(String, String),
+}
+
+/// A point of the route patterns where a parameter or a catch-all can start, or where a route can end.
+/// The static parts following that point are the keys of a radix trie map.
+struct RouteNode<H> {
+    route: Option<(String, H)>,
+    statics: RadixTrieMap<Box<RouteNode<H>>>,
+    parameter: Option<Parameter<H>>,
+    catch_all: Option<CatchAll<H>>,
+}
+
+/// A parameter child of a route node, with the pattern which created it.
+struct Parameter<H> {
+    name: String,
+    pattern: String,
+    node: Box<RouteNode<H>>,
+}
+
+/// A catch-all child of a route node, ending its route.
+struct CatchAll<H> {
+    name: String,
+    pattern: String,
+    handler: H,
+}
+
+/// A part of a route pattern.
+enum Token<'a> {
+    Static(&'a str),
+    Parameter(&'a str),
+    CatchAll(&'a str),
+}
+
+/// Splits a route pattern into its static parts, parameters and catch-all.
+///
+/// # Args:
+///
+/// `pattern` - the pattern to split
+///
+/// # Returns:
+///
+/// the parts of the pattern, or an error if the pattern is invalid
+fn tokenize(pattern: &str) -> Result<Vec<Token<'_>>, RouteError> {
+
+    let invalid = || RouteError::InvalidPattern(pattern.to_string());
+
+    if !pattern.starts_with('/') {
+        return Err(invalid());
+    }
+
+    let mut tokens = Vec::new();
+    let mut names = Vec::new();
+    let mut rest = pattern;
+
+    while !rest.is_empty() {
+
+        let index = match rest.find([':', '*']) {
+            Some(index) => index,
+            None => {
+                tokens.push(Token::Static(rest));
+                break;
+            },
+        };
+
+        if index > 0 {
+            tokens.push(Token::Static(&rest[..index]));
+            rest = &rest[index..];
+            continue;
+        }
+
+        /* the parameter or catch-all name goes up to the next segment */
+
+        let end = rest.find('/').unwrap_or(rest.len());
+        let name = &rest[1..end];
+
+        if name.is_empty() || name.contains([':', '*']) || names.contains(&name) {
+            return Err(invalid());
+        }
+
+        names.push(name);
+
+        if rest.starts_with('*') {
+
+            if end != rest.len() {
+                return Err(invalid());
+            }
+
+            tokens.push(Token::CatchAll(name));
+        } else {
+            tokens.push(Token::Parameter(name));
+        }
+
+        rest = &rest[end..];
+    }
+
+    Ok(tokens)
+}
+
+impl<H> RouteNode<H> {
+
+    /// Creates a new empty route node.
+    ///
+    /// # Returns:
+    ///
+    /// new route node
+    fn new() -> RouteNode<H> {
+
+        RouteNode {
+            route: None,
+            statics: RadixTrieMap::new(),
+            parameter: None,
+            catch_all: None,
+        }
+    }
+
+    /// Recursively browse the existing nodes along the given pattern parts in order to check
+    /// the pattern can be inserted, without modifying the nodes.
+    ///
+    /// # Args:
+    ///
+    /// `tokens` - the pattern parts left to check
+    /// `pattern` - the whole pattern
+    ///
+    /// # Returns:
+    ///
+    /// an error if the pattern is already stored or conflicts with an existing route
+    fn check(&self, tokens: &[Token<'_>], pattern: &str) -> Result<(), RouteError> {
+
+        let (token, rest) = match tokens.split_first() {
+            Some(split) => split,
+            None if self.route.is_some() => return Err(RouteError::DuplicateRoute(pattern.to_string())),
+            None => return Ok(()),
+        };
+
+        let conflict = |existing: &str| RouteError::ConflictingRoute(pattern.to_string(), existing.to_string());
+
+        match *token {
+            Token::Static(text) => match self.statics.get(text) {
+                Some(node) => node.check(rest, pattern),
+                None => Ok(()),
+            },
+            Token::Parameter(name) => {
+
+                if let Some(ref catch_all) = self.catch_all {
+                    return Err(conflict(&catch_all.pattern));
+                }
+
+                match self.parameter {
+                    Some(ref parameter) if parameter.name != name => Err(conflict(&parameter.pattern)),
+                    Some(ref parameter) => parameter.node.check(rest, pattern),
+                    None => Ok(()),
+                }
+            },
+            Token::CatchAll(name) => {
+
+                if let Some(ref parameter) = self.parameter {
+                    return Err(conflict(&parameter.pattern));
+                }
+
+                match self.catch_all {
+                    Some(ref catch_all) if catch_all.name != name => Err(conflict(&catch_all.pattern)),
+                    Some(_) => Err(RouteError::DuplicateRoute(pattern.to_string())),
+                    None => Ok(()),
+                }
+            },
+        }
+    }
+
+    /// Recursively creates the nodes of the given pattern parts, and stores the handler
+    /// at the end of the pattern. The pattern must have been checked first.
+    ///
+    /// # Args:
+    ///
+    /// `tokens` - the pattern parts left to insert
+    /// `pattern` - the whole pattern
+    /// `handler` - the handler of the route
+    fn insert(&mut self, tokens: &[Token<'_>], pattern: &str, handler: H) {
+
+        let (token, rest) = match tokens.split_first() {
+            Some(split) => split,
+            None => {
+                self.route = Some((pattern.to_string(), handler));
+                return;
+            },
+        };
+
+        match *token {
+            Token::Static(text) => {
+                self.statics
+                    .entry(text)
+                    .or_insert_with(|| Box::new(RouteNode::new()))
+                    .insert(rest, pattern, handler);
+            },
+            Token::Parameter(name) => {
+                self.parameter
+                    .get_or_insert_with(|| Parameter {
+                        name: name.to_string(),
+                        pattern: pattern.to_string(),
+                        node: Box::new(RouteNode::new()),
+                    })
+                    .node
+                    .insert(rest, pattern, handler);
+            },
+            Token::CatchAll(name) => {
+                self.catch_all = Some(CatchAll {
+                    name: name.to_string(),
+                    pattern: pattern.to_string(),
+                    handler,
+                });
+            },
+        }
+    }
+
+    /// Recursively searches for the route matching the given path. The static parts are tried
+    /// first, from the longest one, then the parameter, then the catch-all.
+    ///
+    /// # Args:
+    ///
+    /// `path` - the path part left to match
+    /// `params` - the parameters extracted from the path so far
+    ///
+    /// # Returns:
+    ///
+    /// the pattern and the handler of the matching route, or none if no route matches
+    fn resolve<'r, 'p>(&'r self, path: &'p str, params: &mut Vec<(&'r str, &'p str)>) -> Option<(&'r str, &'r H)> {
+
+        if path.is_empty() {
+            if let Some((ref pattern, ref handler)) = self.route {
+                return Some((pattern, handler));
+            }
+        }
+
+        let mut prefixes = Vec::new();
+        self.statics.prefixes_lengths(path, 0, &mut prefixes);
+
+        for (length, node) in prefixes.into_iter().rev() {
+            if let Some(found) = node.resolve(&path[length..], params) {
+                return Some(found);
+            }
+        }
+
+        if let Some(ref parameter) = self.parameter {
+
+            let end = path.find('/').unwrap_or(path.len());
+
+            if end > 0 {
+
+                params.push((&parameter.name, &path[..end]));
+
+                if let Some(found) = parameter.node.resolve(&path[end..], params) {
+                    return Some(found);
+                }
+
+                params.pop();
+            }
+        }
+
+        self.catch_all.as_ref().map(|catch_all| {
+            params.push((&catch_all.name, path));
+            (&catch_all.pattern[..], &catch_all.handler)
+        })
+    }
+}
+
+impl<H> Router<H> {
+
+    /// Creates a new router without any route.
+    ///
+    /// # Returns:
+    ///
+    /// new router
+    pub fn new() -> Router<H> {
+
+        Router {
+            root: RouteNode::new(),
+            count: 0,
+        }
+    }
+
+    /// Inserts a route into the router. Nothing is modified if the route cannot be inserted.
+    ///
+    /// # Arguments:
+    ///
+    /// `pattern` - the pattern of the paths of the route, like `/users/:id/posts/*rest`
+    /// `handler` - the handler of the route
+    ///
+    /// # Returns:
+    ///
+    /// an error if the pattern is invalid, already inserted, or conflicts with an existing route
+    pub fn insert(&mut self, pattern: &str, handler: H) -> Result<(), RouteError> {
+
+        let tokens = tokenize(pattern)?;
+
+        self.root.check(&tokens, pattern)?;
+        self.root.insert(&tokens, pattern, handler);
+        self.count += 1;
+
+        Ok(())
+    }
+
+    /// Resolves a request path into the matching route.
+    ///
+    /// # Arguments:
+    ///
+    /// `path` - the path to resolve
+    ///
+    /// # Returns:
+    ///
+    /// the handler of the matching route with the parameters of the path, or none if no route matches
+    pub fn resolve<'r, 'p>(&'r self, path: &'p str) -> Option<RouteMatch<'r, 'p, H>> {
+
+        let mut params = Vec::new();
+
+        self.root
+            .resolve(path, &mut params)
+            .map(|(pattern, handler)| RouteMatch {
+                pattern,
+                handler,
+                params,
+            })
+    }
+
+    /// Counts the routes of the router.
+    ///
+    /// # Returns:
+    ///
+    /// the amount of routes
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Indicates if the router has no route.
+    ///
+    /// # Returns:
+    ///
+    /// true if no route is stored
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+impl<H> Default for Router<H> {
+
+    fn default() -> Router<H> {
+        Router::new()
+    }
+}
+
+impl<'r, 'p, H> RouteMatch<'r, 'p, H> {
+
+    /// Getter of the pattern of the matching route.
+    ///
+    /// # Returns:
+    ///
+    /// the pattern of the route
+    pub fn pattern(&self) -> &'r str {
+        self.pattern
+    }
+
+    /// Getter of the handler of the matching route.
+    ///
+    /// # Returns:
+    ///
+    /// the handler of the route
+    pub fn handler(&self) -> &'r H {
+        self.handler
+    }
+
+    /// Getter of the parameters extracted from the path, in the order of the pattern.
+    ///
+    /// # Returns:
+    ///
+    /// the names and values of the parameters
+    pub fn params(&self) -> &[(&'r str, &'p str)] {
+        &self.params
+    }
+
+    /// Getter of the value of the given parameter.
+    ///
+    /// # Arguments:
+    ///
+    /// `name` - the name of the parameter
+    ///
+    /// # Returns:
+    ///
+    /// the part of the path matched by the parameter, or none if the route has no such parameter
+    pub fn param(&self, name: &str) -> Option<&'p str> {
+
+        self.params
+            .iter()
+            .find(|&&(param, _)| param == name)
+            .map(|&(_, value)| value)
+    }
+}
+
+impl fmt::Display for RouteError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+
+        match *self {
+            RouteError::InvalidPattern(ref pattern) => {
+                write!(formatter, "invalid route pattern {}", pattern)
+            },
+            RouteError::DuplicateRoute(ref pattern) => {
+                write!(formatter, "route {} is already inserted", pattern)
+            },
+            RouteError::ConflictingRoute(ref pattern, ref existing) => {
+                write!(formatter, "route {} conflicts with route {}", pattern, existing)
+            },
+        }
+    }
+}
+
+impl Error for RouteError {}
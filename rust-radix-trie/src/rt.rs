@@ -0,0 +1,2211 @@
+use std::iter::FromIterator;
+use std::mem;
+use std::ops::{
+    Bound,
+    RangeBounds,
+};
+
+use automaton::{
+    self,
+    Automaton,
+};
+use builder::{
+    BuildError,
+    TrieBuilder,
+};
+use fuzzy::{
+    Distance,
+    FuzzySearch,
+};
+use frozen::{
+    self,
+    FormatError,
+};
+use glob::Glob;
+use iter::{
+    Iter,
+    Keys,
+    Values,
+};
+use label::Label;
+use subtrie::{
+    SubTrie,
+    SubTrieMut,
+};
+
+/// A radix trie node with characters (a string by default, or a byte vector) and children to other nodes.
+/// The value is set if a stored key ends exactly at the end of the node characters.
+/// The amount of keys stored into the node subtree is kept up to date by every insertion
+/// and removal, so the keys can be counted and indexed without browsing the whole trie.
+#[derive(Clone)]
+pub struct RadixTrieMap<V, L = String> {
+    characters: L,
+    children: Vec<RadixTrieMap<V, L>>,
+    value: Option<V>,
+    count: usize,
+}
+
+/// A radix trie storing words without any associated value.
+#[derive(Clone)]
+pub struct RadixTrie {
+    map: RadixTrieMap<()>,
+}
+
+/// A view into a single key of a radix trie map, which may be either occupied or vacant.
+pub enum Entry<'a, V: 'a> {
+    Occupied(OccupiedEntry<'a, V>),
+    Vacant(VacantEntry<'a, V>),
+}
+
+/// A view into a key of a radix trie map that already has a value.
+pub struct OccupiedEntry<'a, V: 'a> {
+    key: String,
+    value: &'a mut V,
+}
+
+/// A view into a key of a radix trie map that has no value yet.
+pub struct VacantEntry<'a, V: 'a> {
+    key: String,
+    map: &'a mut RadixTrieMap<V>,
+}
+
+/// RadixTrieMap creation factory,
+///
+/// # Args:
+///
+/// `characters` - the characters to store into the created node
+/// `value` - the value of the key ending at the end of the node characters, if any
+///
+/// # Returns:
+///
+/// new node
+fn create_node<V, L: Label>(characters: L::Slice<'_>, value: Option<V>) -> RadixTrieMap<V, L> {
+
+    let count = if value.is_some() { 1 } else { 0 };
+
+    RadixTrieMap {
+        characters: L::from_slice(characters),
+        children: Vec::new(),
+        value,
+        count,
+    }
+}
+
+/// RadixTrieMap creation factory from existing children; the keys of the children are counted.
+/// The caller is responsible for the children being sorted and for the node being compressed.
+///
+/// # Args:
+///
+/// `characters` - the characters to store into the created node
+/// `value` - the value of the key ending at the end of the node characters, if any
+/// `children` - the children of the created node
+///
+/// # Returns:
+///
+/// new node
+pub(crate) fn create_node_with_children<V, L: Label>(
+    characters: L,
+    value: Option<V>,
+    children: Vec<RadixTrieMap<V, L>>,
+) -> RadixTrieMap<V, L> {
+
+    let own_count = if value.is_some() { 1 } else { 0 };
+    let count = children.iter().fold(own_count, |count, child| count + child.count);
+
+    RadixTrieMap {
+        characters,
+        children,
+        value,
+        count,
+    }
+}
+
+/// Copies the word of a range bound, so the bound can be kept by an iterator.
+///
+/// # Args:
+///
+/// `bound` - the bound to copy
+///
+/// # Returns:
+///
+/// the bound with an owned word
+fn to_owned_bound(bound: Bound<&&str>) -> Bound<String> {
+
+    match bound {
+        Bound::Included(word) => Bound::Included(word.to_string()),
+        Bound::Excluded(word) => Bound::Excluded(word.to_string()),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// A key given as two pieces compared one after the other, so a word relative to the prefix
+/// of a view is searched for without concatenating the prefix and the word.
+struct KeyPieces<'k, L: Label + 'k> {
+    head: L::Slice<'k>,
+    tail: L::Slice<'k>,
+}
+
+impl<'k, L: Label + 'k> Clone for KeyPieces<'k, L> {
+
+    fn clone(&self) -> KeyPieces<'k, L> {
+        *self
+    }
+}
+
+impl<'k, L: Label + 'k> Copy for KeyPieces<'k, L> {}
+
+impl<'k, L: Label + 'k> KeyPieces<'k, L> {
+
+    /// Creates a key made of two pieces.
+    ///
+    /// # Args:
+    ///
+    /// `head` - the beginning of the key
+    /// `tail` - the end of the key
+    ///
+    /// # Returns:
+    ///
+    /// the key
+    fn new(head: L::Slice<'k>, tail: L::Slice<'k>) -> KeyPieces<'k, L> {
+        KeyPieces { head, tail }
+    }
+
+    /// Creates a key made of a single piece.
+    ///
+    /// # Args:
+    ///
+    /// `key` - the whole key
+    ///
+    /// # Returns:
+    ///
+    /// the key with an empty end
+    fn whole(key: L::Slice<'k>) -> KeyPieces<'k, L> {
+        KeyPieces::new(key, L::split_at(key, L::length(key)).1)
+    }
+
+    /// Getter of the length of the key.
+    ///
+    /// # Returns:
+    ///
+    /// the length of both pieces
+    fn length(&self) -> usize {
+        L::length(self.head) + L::length(self.tail)
+    }
+
+    /// Getter of the first unit of the key.
+    ///
+    /// # Returns:
+    ///
+    /// the first unit, or none if the key is empty
+    fn first_unit(&self) -> Option<L::Unit> {
+        L::first_unit(self.head).or_else(|| L::first_unit(self.tail))
+    }
+
+    /// Removes the beginning of the key.
+    ///
+    /// # Args:
+    ///
+    /// `index` - the length of the beginning to remove, on a unit boundary
+    ///
+    /// # Returns:
+    ///
+    /// the end of the key after the index
+    fn skip(self, index: usize) -> KeyPieces<'k, L> {
+
+        let head_length = L::length(self.head);
+
+        if index <= head_length {
+            return KeyPieces::new(L::split_at(self.head, index).1, self.tail);
+        }
+
+        KeyPieces::whole(L::split_at(self.tail, index - head_length).1)
+    }
+
+    /// Computes the length of the common beginning of the key and the given characters.
+    ///
+    /// # Args:
+    ///
+    /// `characters` - the characters to compare with the key
+    ///
+    /// # Returns:
+    ///
+    /// the index of the first different unit, or the length of the shortest one
+    fn common_length(&self, characters: L::Slice<'_>) -> usize {
+
+        let length = L::common_length(characters, self.head);
+
+        if length < L::length(self.head) || length == L::length(characters) {
+            return length;
+        }
+
+        length + L::common_length(L::split_at(characters, length).1, self.tail)
+    }
+
+    /// Copies the key into a label.
+    ///
+    /// # Returns:
+    ///
+    /// the label of both pieces
+    fn to_label(self) -> L {
+
+        let mut label = L::from_slice(self.head);
+        label.push_slice(self.tail);
+        label
+    }
+}
+
+impl<V, L: Label> RadixTrieMap<V, L> {
+
+    /// Inserts a key with its value into the radix trie map (may create new nodes).
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to store
+    /// `value` - the value to associate to the key
+    ///
+    /// # Returns:
+    ///
+    /// the previous value of the key if the key was already stored, none otherwise
+    pub fn insert(&mut self, key: L::Slice<'_>, value: V) -> Option<V> {
+
+        /* the root node has no characters,
+           so the insertion directly continues into its children */
+
+        self.insert_node(KeyPieces::whole(key), value)
+    }
+
+    /// Inserts a key given as a prefix and a word relative to the prefix, without concatenating them.
+    ///
+    /// # Args:
+    ///
+    /// `prefix` - the beginning of the key
+    /// `word` - the end of the key
+    /// `value` - the value to associate to the key
+    ///
+    /// # Returns:
+    ///
+    /// the previous value of the key if the key was already stored, none otherwise
+    pub(crate) fn insert_pieces<'k>(&mut self, prefix: L::Slice<'k>, word: L::Slice<'k>, value: V) -> Option<V> {
+        self.insert_node(KeyPieces::new(prefix, word), value)
+    }
+
+    /// Recursively browse the radix trie in order to insert the word (may create new nodes).
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the new word to store
+    /// `value` - the value to associate to the word
+    ///
+    /// # Returns:
+    ///
+    /// the previous value of the word if the word was already stored, none otherwise
+    fn insert_node(&mut self, word: KeyPieces<'_, L>, value: V) -> Option<V> {
+
+        let index = match self.contains_pieces(word) {
+            Some(index) => index,
+            None => L::length(self.characters.as_slice()),
+        };
+
+        let word = word.skip(index);
+
+        if index == L::length(self.characters.as_slice()) {
+
+            /* the node characters are entirely part of the word,
+               so the word either ends here or continues into a child */
+
+            if word.length() == 0 {
+
+                let previous = self.value.replace(value);
+
+                if previous.is_none() {
+                    self.count += 1;
+                }
+
+                return previous;
+            }
+
+            if let Ok(child_index) = self.find_unit(word.first_unit()) {
+
+                let previous = self.children[child_index].insert_node(word, value);
+
+                if previous.is_none() {
+                    self.count += 1;
+                }
+
+                return previous;
+            }
+
+            self.add_child(create_node_with_children(word.to_label(), Some(value), Vec::new()));
+            self.count += 1;
+
+            return None;
+        }
+
+        /* in that case, modification of the current node characters
+           is required; the end of the characters, the value and the
+           children of the current node are moved into a new child */
+
+        self.split(index);
+        self.count += 1;
+
+        if word.length() == 0 {
+            self.value = Some(value);
+        } else {
+            self.add_child(create_node_with_children(word.to_label(), Some(value), Vec::new()));
+        }
+
+        None
+    }
+
+    /// Counts the keys stored into the radix trie map (the count is kept by the nodes,
+    /// so no node is browsed).
+    ///
+    /// # Returns:
+    ///
+    /// the amount of stored keys
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Indicates if the radix trie map contains no key. As nodes without keys are
+    /// always removed, only the root node has to be checked.
+    ///
+    /// # Returns:
+    ///
+    /// true if no key is stored
+    pub fn is_empty(&self) -> bool {
+        self.value.is_none() && self.children.is_empty()
+    }
+
+    /// Removes all the keys and values from the radix trie map.
+    pub fn clear(&mut self) {
+        self.children.clear();
+        self.value = None;
+        self.count = 0;
+    }
+
+    /// Creates an iterator over all the keys and values, in lexicographic order of the keys.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the keys and values
+    pub fn iter(&self) -> Iter<'_, V, L> {
+        Iter::new(self, L::default().as_slice())
+    }
+
+    /// Creates an iterator over all the keys, in lexicographic order.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the keys
+    pub fn keys(&self) -> Keys<'_, V, L> {
+        Keys::new(self.iter())
+    }
+
+    /// Creates an iterator over all the values, in lexicographic order of their keys.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the values
+    pub fn values(&self) -> Values<'_, V, L> {
+        Values::new(self)
+    }
+
+    /// Creates an iterator over the keys starting with the given prefix and their values,
+    /// in lexicographic order of the keys.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the beginning of the keys to iterate over
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the keys and values
+    pub fn iter_prefix(&self, prefix: L::Slice<'_>) -> Iter<'_, V, L> {
+
+        match self.get_prefix_node(prefix) {
+            Some((node, length)) => Iter::new(node, L::split_at(prefix, length).0),
+            None => Iter::empty(),
+        }
+    }
+
+    /// Creates an iterator over the values of the keys starting with the given prefix,
+    /// in lexicographic order of the keys. The keys are not built.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the beginning of the keys whose values are iterated over
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the values
+    pub fn values_prefix(&self, prefix: L::Slice<'_>) -> Values<'_, V, L> {
+
+        match self.get_prefix_node(prefix) {
+            Some((node, _)) => Values::new(node),
+            None => Values::empty(),
+        }
+    }
+
+    /// Searches for the longest stored key which is a prefix of the given query.
+    ///
+    /// # Arguments:
+    ///
+    /// `query` - the word to match
+    ///
+    /// # Returns:
+    ///
+    /// the longest key (as a part of the query) with its value, or none if no key is a prefix of the query
+    pub fn longest_prefix<'a, 'b>(&'a self, query: L::Slice<'b>) -> Option<(L::Slice<'b>, &'a V)> {
+
+        self.longest_prefix_length(query)
+            .map(|(length, value)| (L::split_at(query, length).0, value))
+    }
+
+    /// Getter of the value associated to the given key.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to search for
+    ///
+    /// # Returns:
+    ///
+    /// the value of the key or none if the key is not stored
+    pub fn get(&self, key: L::Slice<'_>) -> Option<&V> {
+        self.get_node(KeyPieces::whole(key)).and_then(|node| node.value.as_ref())
+    }
+
+    /// Getter of the value of a key given as the characters stored into the node
+    /// followed by the characters to search for into its subtree.
+    ///
+    /// # Args:
+    ///
+    /// `stored` - the beginning of the node characters, which is skipped
+    /// `key` - the end of the key to search for after the skipped characters
+    ///
+    /// # Returns:
+    ///
+    /// the value of the key or none if the key is not stored
+    pub(crate) fn get_pieces<'k>(&self, stored: L::Slice<'k>, key: L::Slice<'k>) -> Option<&V> {
+        self.get_node(KeyPieces::new(stored, key)).and_then(|node| node.value.as_ref())
+    }
+
+    /// Mutable getter of the value associated to the given key.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to search for
+    ///
+    /// # Returns:
+    ///
+    /// the mutable value of the key or none if the key is not stored
+    pub fn get_mut(&mut self, key: L::Slice<'_>) -> Option<&mut V> {
+        self.get_node_mut(key).and_then(|node| node.value.as_mut())
+    }
+
+    /// Removes the given key from the radix trie map. Nodes without any key are removed
+    /// and nodes with a single child are merged with it, so the trie stays compressed.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to remove
+    ///
+    /// # Returns:
+    ///
+    /// the value of the removed key or none if the key was not stored
+    pub fn remove(&mut self, key: L::Slice<'_>) -> Option<V> {
+
+        /* the root node is never pruned nor merged,
+           even if it has no value and a single child */
+
+        self.remove_node(KeyPieces::whole(key))
+    }
+
+    /// Removes a key given as a prefix and a word relative to the prefix, without concatenating them.
+    ///
+    /// # Args:
+    ///
+    /// `prefix` - the beginning of the key
+    /// `word` - the end of the key
+    ///
+    /// # Returns:
+    ///
+    /// the value of the removed key or none if the key was not stored
+    pub(crate) fn remove_pieces<'k>(&mut self, prefix: L::Slice<'k>, word: L::Slice<'k>) -> Option<V> {
+        self.remove_node(KeyPieces::new(prefix, word))
+    }
+
+    /// Recursively browse the radix trie in order to remove the word. The parent node
+    /// of each browsed child prunes or merges the child once the word has been removed.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to remove
+    ///
+    /// # Returns:
+    ///
+    /// the value of the removed word or none if the word was not stored
+    fn remove_node(&mut self, word: KeyPieces<'_, L>) -> Option<V> {
+
+        if self.contains_pieces(word).is_some() {
+            return None;
+        }
+
+        let word = word.skip(L::length(self.characters.as_slice()));
+
+        if word.length() == 0 {
+
+            let value = self.value.take();
+
+            if value.is_some() {
+                self.count -= 1;
+            }
+
+            return value;
+        }
+
+        let index = self.find_unit(word.first_unit()).ok()?;
+
+        let value = self.children[index].remove_node(word)?;
+
+        self.count -= 1;
+
+        if self.children[index].value.is_some() {
+            return Some(value);
+        }
+
+        match self.children[index].children.len() {
+            0 => {
+                self.children.remove(index);
+            },
+            1 => {
+                self.children[index].merge_child();
+            },
+            _ => {},
+        }
+
+        Some(value)
+    }
+
+    /// Indicates if the given key has been inserted into the radix trie map.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if the key has been inserted, False if it has not
+    pub fn contains_key(&self, key: L::Slice<'_>) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Indicates if at least one stored key starts with the given prefix.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the beginning of the keys to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if one key at least starts with the prefix, False otherwise
+    pub fn has_prefix(&self, prefix: L::Slice<'_>) -> bool {
+        self.has_prefix_node(KeyPieces::whole(prefix))
+    }
+
+    /// Indicates if at least one stored key starts with the given prefix, given as the characters
+    /// stored into the node followed by the characters to search for into its subtree.
+    ///
+    /// # Args:
+    ///
+    /// `stored` - the beginning of the node characters, which is skipped
+    /// `prefix` - the beginning of the keys to search for after the skipped characters
+    ///
+    /// # Returns:
+    ///
+    /// True if one key at least starts with the prefix, False otherwise
+    pub(crate) fn has_prefix_pieces<'k>(&self, stored: L::Slice<'k>, prefix: L::Slice<'k>) -> bool {
+        self.has_prefix_node(KeyPieces::new(stored, prefix))
+    }
+
+    /// Recursively browse the radix trie in order to find a key starting with the given prefix.
+    ///
+    /// # Args:
+    ///
+    /// `prefix` - the beginning of the keys to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if one key at least starts with the prefix, False otherwise
+    fn has_prefix_node(&self, prefix: KeyPieces<'_, L>) -> bool {
+
+        if let Some(index) = self.contains_pieces(prefix) {
+
+            /* the prefix may end in the middle of the node characters */
+
+            return index == prefix.length();
+        }
+
+        if L::length(self.characters.as_slice()) == prefix.length() {
+
+            /* only the root node may have neither value nor children */
+
+            return self.value.is_some() || !self.children.is_empty();
+        }
+
+        let second = prefix.skip(L::length(self.characters.as_slice()));
+
+        match self.find_unit(second.first_unit()) {
+            Ok(index) => self.children[index].has_prefix_node(second),
+            Err(_) => false,
+        }
+    }
+
+    /// Recursively browse the radix trie in order to find the deepest node that terminates a key
+    /// which is a prefix of the given query.
+    ///
+    /// # Args:
+    ///
+    /// `query` - the word to match
+    ///
+    /// # Returns:
+    ///
+    /// the length of the longest key which is a prefix of the query with its value, or none
+    fn longest_prefix_length(&self, query: L::Slice<'_>) -> Option<(usize, &V)> {
+
+        if self.contains_word(query).is_some() {
+            return None;
+        }
+
+        let length = L::length(self.characters.as_slice());
+        let (_, second) = L::split_at(query, length);
+
+        let current = self.value
+            .as_ref()
+            .map(|value| (length, value));
+
+        if L::length(second) == 0 {
+            return current;
+        }
+
+        let deeper = match self.find_child(second) {
+            Ok(index) => self.children[index].longest_prefix_length(second),
+            Err(_) => None,
+        };
+
+        deeper
+            .map(|(child_length, value)| (length + child_length, value))
+            .or(current)
+    }
+
+    /// Recursively browse the radix trie in order to find all the nodes that terminate a key
+    /// which is a prefix of the given query.
+    ///
+    /// # Args:
+    ///
+    /// `query` - the word to match
+    /// `offset` - the length of the query part stored into the parents of the node
+    /// `prefixes` - the found keys lengths with their values, from the shortest key to the longest
+    pub(crate) fn prefixes_lengths<'a>(
+        &'a self,
+        query: L::Slice<'_>,
+        offset: usize,
+        prefixes: &mut Vec<(usize, &'a V)>,
+    ) {
+
+        if self.contains_word(query).is_some() {
+            return;
+        }
+
+        let length = L::length(self.characters.as_slice());
+        let (_, second) = L::split_at(query, length);
+
+        if let Some(value) = self.value.as_ref() {
+            prefixes.push((offset + length, value));
+        }
+
+        if L::length(second) == 0 {
+            return;
+        }
+
+        if let Ok(index) = self.find_child(second) {
+            self.children[index].prefixes_lengths(second, offset + length, prefixes);
+        }
+    }
+
+    /// Recursively browse the radix trie in order to find the node where the given prefix ends.
+    /// The prefix may end in the middle of the node characters.
+    ///
+    /// # Args:
+    ///
+    /// `prefix` - the prefix to search for
+    ///
+    /// # Returns:
+    ///
+    /// the node where the prefix ends with the length of the prefix part stored into
+    /// the parents of the node, or none if no key starts with the prefix
+    pub(crate) fn get_prefix_node(&self, prefix: L::Slice<'_>) -> Option<(&RadixTrieMap<V, L>, usize)> {
+
+        if let Some(index) = self.contains_word(prefix) {
+
+            if index == L::length(prefix) {
+                return Some((self, 0));
+            }
+
+            return None;
+        }
+
+        let (_, second) = L::split_at(prefix, L::length(self.characters.as_slice()));
+
+        if L::length(second) == 0 {
+            return Some((self, 0));
+        }
+
+        let index = self.find_child(second).ok()?;
+
+        self.children[index]
+            .get_prefix_node(second)
+            .map(|(node, length)| (node, length + L::length(self.characters.as_slice())))
+    }
+
+    /// Recursively browse the radix trie in order to find the node where the given key ends.
+    ///
+    /// # Args:
+    ///
+    /// `key` - the key to search for
+    ///
+    /// # Returns:
+    ///
+    /// the node where the key ends or none if the key does not end at the end of a node characters
+    fn get_node(&self, key: KeyPieces<'_, L>) -> Option<&RadixTrieMap<V, L>> {
+
+        if self.contains_pieces(key).is_some() {
+            return None;
+        }
+
+        let second = key.skip(L::length(self.characters.as_slice()));
+
+        if second.length() == 0 {
+            return Some(self);
+        }
+
+        let index = self.find_unit(second.first_unit()).ok()?;
+
+        self.children[index].get_node(second)
+    }
+
+    /// Recursively browse the radix trie in order to find the node where the given key ends.
+    ///
+    /// # Args:
+    ///
+    /// `key` - the key to search for
+    ///
+    /// # Returns:
+    ///
+    /// the mutable node where the key ends or none if the key does not end at the end of a node characters
+    fn get_node_mut(&mut self, key: L::Slice<'_>) -> Option<&mut RadixTrieMap<V, L>> {
+
+        if self.contains_word(key).is_some() {
+            return None;
+        }
+
+        let (_, second) = L::split_at(key, L::length(self.characters.as_slice()));
+
+        if L::length(second) == 0 {
+            return Some(self);
+        }
+
+        let index = self.find_child(second).ok()?;
+
+        self.children[index].get_node_mut(second)
+    }
+
+    /// Indicates if the node contains the given word. That means if the word is the beginning of the node contained characters, or if the word is exactly the node characters.
+    ///
+    /// # Args:
+    ///
+    /// `word` - the word to find
+    ///
+    /// # Returns:
+    ///
+    /// The index of the first different character between the two words (or the word length
+    /// if the word ends before the node characters) or none if no difference is found after
+    /// browsing the node characters and comparing with the word; the index is always on a
+    /// character boundary, so it can safely be used to split the node characters
+    fn contains_word(&self, word: L::Slice<'_>) -> Option<usize> {
+        self.contains_pieces(KeyPieces::whole(word))
+    }
+
+    /// Indicates if the node contains the given word made of two pieces, as `contains_word`.
+    ///
+    /// # Args:
+    ///
+    /// `word` - the word to find
+    ///
+    /// # Returns:
+    ///
+    /// the index of the first different character between the two words, or none
+    /// if the node characters are entirely part of the word
+    fn contains_pieces(&self, word: KeyPieces<'_, L>) -> Option<usize> {
+
+        let characters = self.characters.as_slice();
+        let index = word.common_length(characters);
+
+        if index < L::length(characters) {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Searches for the child starting with the same character as the given word.
+    /// Children are sorted by their first character, which is different for every child.
+    ///
+    /// # Args:
+    ///
+    /// `word` - the word to compare with the children characters
+    ///
+    /// # Returns:
+    ///
+    /// the index of the child starting with the same character as the word, or the index
+    /// where such a child would have to be inserted to keep the children sorted
+    fn find_child(&self, word: L::Slice<'_>) -> Result<usize, usize> {
+        self.find_unit(L::first_unit(word))
+    }
+
+    /// Searches for the child starting with the given character.
+    ///
+    /// # Args:
+    ///
+    /// `unit` - the first character of the searched child
+    ///
+    /// # Returns:
+    ///
+    /// the index of the child starting with the character, or the index where such
+    /// a child would have to be inserted to keep the children sorted
+    fn find_unit(&self, unit: Option<L::Unit>) -> Result<usize, usize> {
+
+        self.children
+            .binary_search_by(|child| L::first_unit(child.characters.as_slice()).cmp(&unit))
+    }
+
+    /// Inserts the given node as a child, keeping the children sorted by their first character.
+    ///
+    /// # Args:
+    ///
+    /// `child` - the child to insert
+    fn add_child(&mut self, child: RadixTrieMap<V, L>) {
+
+        let index = match self.find_child(child.characters.as_slice()) {
+            Ok(index) | Err(index) => index,
+        };
+
+        self.children.insert(index, child);
+    }
+
+    /// Merges the node with its unique child: the child characters are appended to the node characters,
+    /// and the node takes the value and the children of the child.
+    fn merge_child(&mut self) {
+
+        let child = self.children.pop().unwrap();
+
+        self.characters.push_slice(child.characters.as_slice());
+        self.value = child.value;
+        self.children = child.children;
+    }
+
+    /// Moves the node characters after the given index, the node value and the node children
+    /// into a single new child. The subtree is moved and not copied, so the cost of a split
+    /// only depends on the length of the node characters, and not on the size of the subtree.
+    ///
+    /// # Args:
+    ///
+    /// `separator` - the index of the separator where the node characters have to be divided
+    fn split(&mut self, separator: usize) {
+
+        let (_, moved_characters) = L::split_at(self.characters.as_slice(), separator);
+        let moved_characters = L::from_slice(moved_characters);
+
+        self.characters.truncate(separator);
+
+        let child = RadixTrieMap {
+            characters: moved_characters,
+            children: mem::take(&mut self.children),
+            value: self.value.take(),
+            count: self.count,
+        };
+
+        self.add_child(child);
+    }
+
+    /// Getter of the characters stored into the node.
+    ///
+    /// # Returns:
+    ///
+    /// the characters into the node
+    pub fn get_characters(&self) -> L::Slice<'_> {
+        self.characters.as_slice()
+    }
+
+    /// Getter of the children of the node.
+    ///
+    /// # Returns:
+    ///
+    /// list of children
+    pub fn get_children(&self) -> &Vec<RadixTrieMap<V, L>> {
+        &self.children
+    }
+
+    /// Getter of the value stored into the node.
+    ///
+    /// # Returns:
+    ///
+    /// the value of the key ending at the end of the node characters, if any
+    pub fn get_value(&self) -> Option<&V> {
+        self.value.as_ref()
+    }
+
+    /// Indicates if a stored key ends at the end of the node characters.
+    ///
+    /// # Returns:
+    ///
+    /// true if the node terminates a key
+    pub fn is_terminal(&self) -> bool {
+        self.value.is_some()
+    }
+}
+
+impl<V> RadixTrieMap<V> {
+
+    /// Creates a new empty radix trie map, with a root node that has no characters and no children.
+    ///
+    /// # Returns:
+    ///
+    /// new radix trie map
+    pub fn new() -> RadixTrieMap<V> {
+        create_node("", None)
+    }
+
+    /// Creates a new radix trie map from keys sorted in lexicographic order, in a single pass
+    /// (faster than inserting the keys one by one).
+    ///
+    /// # Arguments:
+    ///
+    /// `iter` - the keys with their values, sorted by key
+    ///
+    /// # Returns:
+    ///
+    /// new radix trie map, or an error if a key is not bigger than the previous one
+    pub fn from_sorted_iter<'a, I: IntoIterator<Item = (&'a str, V)>>(iter: I) -> Result<RadixTrieMap<V>, BuildError> {
+
+        let mut builder = TrieBuilder::new();
+
+        for (key, value) in iter {
+            builder.push(key, value)?;
+        }
+
+        Ok(builder.build())
+    }
+
+    /// Creates an iterator over the keys of the given range and their values,
+    /// in lexicographic order of the keys.
+    ///
+    /// # Arguments:
+    ///
+    /// `range` - the range of the keys to iterate over
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the keys and values
+    pub fn range<'r, R: RangeBounds<&'r str>>(&self, range: R) -> Iter<'_, V> {
+
+        Iter::new(self, "").with_bounds(
+            to_owned_bound(range.start_bound()),
+            to_owned_bound(range.end_bound()),
+        )
+    }
+
+    /// Searches for the smallest key, descending the first child of every node.
+    ///
+    /// # Returns:
+    ///
+    /// the smallest key with its value, or none if the map is empty
+    pub fn first(&self) -> Option<(String, &V)> {
+
+        let mut key = String::new();
+        self.first_node(&mut key).map(|value| (key, value))
+    }
+
+    /// Searches for the biggest key, descending the last child of every node.
+    ///
+    /// # Returns:
+    ///
+    /// the biggest key with its value, or none if the map is empty
+    pub fn last(&self) -> Option<(String, &V)> {
+
+        let mut key = String::new();
+        self.last_node(&mut key).map(|value| (key, value))
+    }
+
+    /// Searches for the smallest key strictly bigger than the given one.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to compare with (it does not have to be stored)
+    ///
+    /// # Returns:
+    ///
+    /// the next key with its value, or none if no key is bigger
+    pub fn successor(&self, key: &str) -> Option<(String, &V)> {
+
+        let mut successor = String::new();
+        self.successor_node(key, false, &mut successor)
+            .map(|value| (successor, value))
+    }
+
+    /// Searches for the smallest key bigger than or equal to the given one.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to compare with (it does not have to be stored)
+    ///
+    /// # Returns:
+    ///
+    /// the key itself if stored or the next key, with its value, or none if no key is bigger
+    pub fn successor_or_equal(&self, key: &str) -> Option<(String, &V)> {
+
+        let mut successor = String::new();
+        self.successor_node(key, true, &mut successor)
+            .map(|value| (successor, value))
+    }
+
+    /// Searches for the biggest key strictly smaller than the given one.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to compare with (it does not have to be stored)
+    ///
+    /// # Returns:
+    ///
+    /// the previous key with its value, or none if no key is smaller
+    pub fn predecessor(&self, key: &str) -> Option<(String, &V)> {
+
+        let mut predecessor = String::new();
+        self.predecessor_node(key, false, &mut predecessor)
+            .map(|value| (predecessor, value))
+    }
+
+    /// Searches for the biggest key smaller than or equal to the given one.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to compare with (it does not have to be stored)
+    ///
+    /// # Returns:
+    ///
+    /// the key itself if stored or the previous key, with its value, or none if no key is smaller
+    pub fn predecessor_or_equal(&self, key: &str) -> Option<(String, &V)> {
+
+        let mut predecessor = String::new();
+        self.predecessor_node(key, true, &mut predecessor)
+            .map(|value| (predecessor, value))
+    }
+
+    /// Counts the keys smaller than the given key, using the counts of the nodes on the path
+    /// of the key. If the key is stored, that is its index in the lexicographic order.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key to compare with (it does not have to be stored)
+    ///
+    /// # Returns:
+    ///
+    /// the amount of stored keys smaller than the key
+    pub fn rank(&self, key: &str) -> usize {
+        self.rank_node(key)
+    }
+
+    /// Searches for the key at the given index in the lexicographic order, skipping
+    /// the children whose keys are all before the index.
+    ///
+    /// # Arguments:
+    ///
+    /// `index` - the index of the key to search for
+    ///
+    /// # Returns:
+    ///
+    /// the key with its value, or none if the index is not smaller than the amount of keys
+    pub fn select(&self, index: usize) -> Option<(String, &V)> {
+
+        let mut key = String::new();
+        self.select_node(index, &mut key).map(|value| (key, value))
+    }
+
+    /// Lists the keys within the given Levenshtein distance of the query, sorted by distance
+    /// then in lexicographic order. Characters shared by several keys are compared only once.
+    ///
+    /// # Arguments:
+    ///
+    /// `query` - the word to compare the keys with
+    /// `max_distance` - the maximum amount of edits between the query and the keys
+    ///
+    /// # Returns:
+    ///
+    /// the found keys with their values and distances
+    pub fn fuzzy_search(&self, query: &str, max_distance: usize) -> Vec<(String, &V, usize)> {
+        self.fuzzy_search_with(query, max_distance, Distance::Levenshtein)
+    }
+
+    /// Lists the keys within the given distance of the query, sorted by distance
+    /// then in lexicographic order.
+    ///
+    /// # Arguments:
+    ///
+    /// `query` - the word to compare the keys with
+    /// `max_distance` - the maximum amount of edits between the query and the keys
+    /// `distance` - the edit distance to use
+    ///
+    /// # Returns:
+    ///
+    /// the found keys with their values and distances
+    pub fn fuzzy_search_with(
+        &self,
+        query: &str,
+        max_distance: usize,
+        distance: Distance,
+    ) -> Vec<(String, &V, usize)> {
+        FuzzySearch::new(query, max_distance, distance).search(self)
+    }
+
+    /// Lists the keys matching the given pattern, in lexicographic order. The pattern may contain
+    /// `?` (any single character) and `*` (any run of characters, including an empty one).
+    /// The subtrees whose characters cannot match the pattern are not browsed.
+    ///
+    /// # Arguments:
+    ///
+    /// `pattern` - the pattern of the keys to list
+    ///
+    /// # Returns:
+    ///
+    /// the matching keys with their values
+    pub fn matches(&self, pattern: &str) -> Vec<(String, &V)> {
+        self.search_automaton(&Glob::new(pattern))
+    }
+
+    /// Lists the keys accepted by the given automaton, in lexicographic order. The subtrees
+    /// are not browsed once the automaton cannot reach a match anymore.
+    ///
+    /// # Arguments:
+    ///
+    /// `automaton` - the automaton accepting the keys to list
+    ///
+    /// # Returns:
+    ///
+    /// the accepted keys with their values
+    pub fn search_automaton<A: Automaton>(&self, automaton: &A) -> Vec<(String, &V)> {
+        automaton::search(automaton, self)
+    }
+
+    /// Gets the entry of the given key for in-place manipulation.
+    ///
+    /// # Arguments:
+    ///
+    /// `key` - the key of the entry
+    ///
+    /// # Returns:
+    ///
+    /// occupied entry if the key is stored, vacant entry otherwise
+    pub fn entry(&mut self, key: &str) -> Entry<'_, V> {
+
+        if !self.contains_key(key) {
+
+            return Entry::Vacant(
+                VacantEntry {
+                    key: key.to_string(),
+                    map: self,
+                }
+            );
+        }
+
+        Entry::Occupied(
+            OccupiedEntry {
+                key: key.to_string(),
+                value: self.get_mut(key).unwrap(),
+            }
+        )
+    }
+
+    /// Recursively browse the first children of the nodes in order to find the smallest key.
+    ///
+    /// # Args:
+    ///
+    /// `key` - the characters of the parents, completed with the found key characters
+    ///
+    /// # Returns:
+    ///
+    /// the value of the smallest key of the node subtree, or none if the subtree is empty
+    fn first_node(&self, key: &mut String) -> Option<&V> {
+
+        let length = key.len();
+        key.push_str(&self.characters);
+
+        /* a key is always smaller than the keys it is the prefix of */
+
+        let found = self.value.as_ref().or_else(|| {
+            self.children
+                .iter()
+                .find_map(|child| child.first_node(key))
+        });
+
+        if found.is_none() {
+            key.truncate(length);
+        }
+
+        found
+    }
+
+    /// Recursively browse the last children of the nodes in order to find the biggest key.
+    ///
+    /// # Args:
+    ///
+    /// `key` - the characters of the parents, completed with the found key characters
+    ///
+    /// # Returns:
+    ///
+    /// the value of the biggest key of the node subtree, or none if the subtree is empty
+    fn last_node(&self, key: &mut String) -> Option<&V> {
+
+        let length = key.len();
+        key.push_str(&self.characters);
+
+        let found = self.children
+            .iter()
+            .rev()
+            .find_map(|child| child.last_node(key))
+            .or(self.value.as_ref());
+
+        if found.is_none() {
+            key.truncate(length);
+        }
+
+        found
+    }
+
+    /// Recursively browse the radix trie in order to find the smallest key bigger than the given word.
+    /// Only the nodes on the path of the word are compared, the other subtrees are either
+    /// entirely smaller or entirely bigger than the word.
+    ///
+    /// # Args:
+    ///
+    /// `word` - the word to compare with, starting with the node characters part
+    /// `inclusive` - true if the word itself can be returned
+    /// `key` - the characters of the parents, completed with the found key characters
+    ///
+    /// # Returns:
+    ///
+    /// the value of the found key, or none if no key of the node subtree is bigger
+    fn successor_node(&self, word: &str, inclusive: bool, key: &mut String) -> Option<&V> {
+
+        if let Some(index) = self.contains_word(word) {
+
+            /* the word ends or diverges inside the node characters,
+               so the whole subtree is either bigger or smaller */
+
+            if self.characters[index..] > word[index..] {
+                return self.first_node(key);
+            }
+
+            return None;
+        }
+
+        let length = key.len();
+        key.push_str(&self.characters);
+
+        let (_, second) = word.split_at(self.characters.len());
+
+        let found = if second.is_empty() {
+
+            let current = if inclusive { self.value.as_ref() } else { None };
+
+            current.or_else(|| {
+                self.children
+                    .iter()
+                    .find_map(|child| child.first_node(key))
+            })
+        } else {
+
+            let (next, bigger) = match self.find_child(second) {
+                Ok(index) => (index + 1, self.children[index].successor_node(second, inclusive, key)),
+                Err(index) => (index, None),
+            };
+
+            /* the children after the searched one only contain bigger keys */
+
+            bigger.or_else(|| {
+                self.children[next..]
+                    .iter()
+                    .find_map(|child| child.first_node(key))
+            })
+        };
+
+        if found.is_none() {
+            key.truncate(length);
+        }
+
+        found
+    }
+
+    /// Recursively browse the radix trie in order to find the biggest key smaller than the given word.
+    /// Only the nodes on the path of the word are compared, the other subtrees are either
+    /// entirely smaller or entirely bigger than the word.
+    ///
+    /// # Args:
+    ///
+    /// `word` - the word to compare with, starting with the node characters part
+    /// `inclusive` - true if the word itself can be returned
+    /// `key` - the characters of the parents, completed with the found key characters
+    ///
+    /// # Returns:
+    ///
+    /// the value of the found key, or none if no key of the node subtree is smaller
+    fn predecessor_node(&self, word: &str, inclusive: bool, key: &mut String) -> Option<&V> {
+
+        if let Some(index) = self.contains_word(word) {
+
+            /* the word ends or diverges inside the node characters,
+               so the whole subtree is either bigger or smaller */
+
+            if self.characters[index..] < word[index..] {
+                return self.last_node(key);
+            }
+
+            return None;
+        }
+
+        let length = key.len();
+        key.push_str(&self.characters);
+
+        let (_, second) = word.split_at(self.characters.len());
+
+        let found = if second.is_empty() {
+
+            /* the keys of the children all start with the word, so they are bigger */
+
+            if inclusive { self.value.as_ref() } else { None }
+        } else {
+
+            let (index, smaller) = match self.find_child(second) {
+                Ok(index) => (index, self.children[index].predecessor_node(second, inclusive, key)),
+                Err(index) => (index, None),
+            };
+
+            /* the children before the searched one only contain smaller keys,
+               and the node key is smaller than all of them */
+
+            smaller
+                .or_else(|| {
+                    self.children[..index]
+                        .iter()
+                        .rev()
+                        .find_map(|child| child.last_node(key))
+                })
+                .or(self.value.as_ref())
+        };
+
+        if found.is_none() {
+            key.truncate(length);
+        }
+
+        found
+    }
+
+    /// Recursively browse the radix trie in order to count the keys smaller than the given word.
+    ///
+    /// # Args:
+    ///
+    /// `word` - the word to compare with, starting with the node characters part
+    ///
+    /// # Returns:
+    ///
+    /// the amount of keys of the node subtree smaller than the word
+    fn rank_node(&self, word: &str) -> usize {
+
+        if let Some(index) = self.contains_word(word) {
+
+            /* the word ends or diverges inside the node characters,
+               so the whole subtree is either bigger or smaller */
+
+            if self.characters[index..] < word[index..] {
+                return self.count;
+            }
+
+            return 0;
+        }
+
+        let (_, second) = word.split_at(self.characters.len());
+
+        if second.is_empty() {
+            return 0;
+        }
+
+        /* the node key is a prefix of the word, so it is smaller */
+
+        let current = if self.value.is_some() { 1 } else { 0 };
+
+        let (index, deeper) = match self.find_child(second) {
+            Ok(index) => (index, self.children[index].rank_node(second)),
+            Err(index) => (index, 0),
+        };
+
+        self.children[..index]
+            .iter()
+            .fold(current + deeper, |count, child| count + child.count)
+    }
+
+    /// Recursively browse the radix trie in order to find the key at the given index.
+    ///
+    /// # Args:
+    ///
+    /// `index` - the index of the key into the node subtree
+    /// `key` - the characters of the parents, completed with the found key characters
+    ///
+    /// # Returns:
+    ///
+    /// the value of the found key, or none if the node subtree has not enough keys
+    fn select_node(&self, index: usize, key: &mut String) -> Option<&V> {
+
+        if index >= self.count {
+            return None;
+        }
+
+        key.push_str(&self.characters);
+
+        let mut index = index;
+
+        if let Some(ref value) = self.value {
+
+            if index == 0 {
+                return Some(value);
+            }
+
+            index -= 1;
+        }
+
+        for child in self.children.iter() {
+
+            if index < child.count {
+                return child.select_node(index, key);
+            }
+
+            index -= child.count;
+        }
+
+        None
+    }
+}
+
+impl RadixTrie {
+
+    /// Creates a new empty radix trie, with an empty array of characters and an empty list of children nodes.
+    ///
+    /// # Returns:
+    ///
+    /// new radix trie
+    pub fn new() -> RadixTrie {
+
+        RadixTrie {
+            map: RadixTrieMap::new(),
+        }
+    }
+
+    /// Creates a radix trie from its root node.
+    ///
+    /// # Args:
+    ///
+    /// `map` - the root node of the trie
+    ///
+    /// # Returns:
+    ///
+    /// new radix trie
+    pub(crate) fn from_map(map: RadixTrieMap<()>) -> RadixTrie {
+        RadixTrie { map }
+    }
+
+    /// Creates a new radix trie containing the given words.
+    ///
+    /// # Arguments:
+    ///
+    /// `words` - the words to store
+    ///
+    /// # Returns:
+    ///
+    /// new radix trie
+    pub fn with_words<'a, I: IntoIterator<Item = &'a str>>(words: I) -> RadixTrie {
+        words.into_iter().collect()
+    }
+
+    /// Creates a new radix trie from words sorted in lexicographic order, in a single pass
+    /// (faster than inserting the words one by one).
+    ///
+    /// # Arguments:
+    ///
+    /// `words` - the sorted words to store
+    ///
+    /// # Returns:
+    ///
+    /// new radix trie, or an error if a word is not bigger than the previous one
+    pub fn from_sorted_iter<'a, I: IntoIterator<Item = &'a str>>(words: I) -> Result<RadixTrie, BuildError> {
+
+        RadixTrieMap::from_sorted_iter(words.into_iter().map(|word| (word, ())))
+            .map(RadixTrie::from_map)
+    }
+
+    /// Inserts a new word into the radix trie (may create new nodes).
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the new word to store
+    ///
+    /// # Returns:
+    ///
+    /// true if the word was not already stored
+    pub fn insert(&mut self, word: &str) -> bool {
+        self.map.insert(word, ()).is_none()
+    }
+
+    /// Counts the words stored into the radix trie.
+    ///
+    /// # Returns:
+    ///
+    /// the amount of stored words
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Indicates if the radix trie contains no word.
+    ///
+    /// # Returns:
+    ///
+    /// true if no word is stored
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Removes all the words from the radix trie.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Creates an iterator over all the words, in lexicographic order.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the words
+    pub fn iter(&self) -> Keys<'_, ()> {
+        self.map.keys()
+    }
+
+    /// Creates an iterator over the words starting with the given prefix, in lexicographic order.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the beginning of the words to iterate over
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the words
+    pub fn iter_prefix(&self, prefix: &str) -> Keys<'_, ()> {
+        Keys::new(self.map.iter_prefix(prefix))
+    }
+
+    /// Creates a read-only view over the words starting with the given prefix.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the prefix of the view
+    ///
+    /// # Returns:
+    ///
+    /// the view or none if no word starts with the prefix
+    pub fn subtrie(&self, prefix: &str) -> Option<SubTrie<'_>> {
+
+        if !self.exists(prefix) {
+            return None;
+        }
+
+        self.map
+            .get_prefix_node(prefix)
+            .map(|(node, offset)| SubTrie::new(node, prefix, offset))
+    }
+
+    /// Creates a mutable view over the words starting with the given prefix.
+    /// The view can be created for a prefix without any word, in order to fill it.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the prefix of the view
+    ///
+    /// # Returns:
+    ///
+    /// the view
+    pub fn subtrie_mut(&mut self, prefix: &str) -> SubTrieMut<'_> {
+        SubTrieMut::new(&mut self.map, prefix)
+    }
+
+    /// Searches for the longest stored word which is a prefix of the given query.
+    ///
+    /// # Arguments:
+    ///
+    /// `query` - the word to match
+    ///
+    /// # Returns:
+    ///
+    /// the longest word (as a part of the query), or none if no word is a prefix of the query
+    pub fn longest_prefix<'a>(&self, query: &'a str) -> Option<&'a str> {
+        self.map.longest_prefix(query).map(|(word, _)| word)
+    }
+
+    /// Creates an iterator over the words of the given range, in lexicographic order.
+    ///
+    /// # Arguments:
+    ///
+    /// `range` - the range of the words to iterate over
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the words
+    pub fn range<'r, R: RangeBounds<&'r str>>(&self, range: R) -> Keys<'_, ()> {
+        Keys::new(self.map.range(range))
+    }
+
+    /// Searches for the smallest word.
+    ///
+    /// # Returns:
+    ///
+    /// the smallest word, or none if the trie is empty
+    pub fn first(&self) -> Option<String> {
+        self.map.first().map(|(word, _)| word)
+    }
+
+    /// Searches for the biggest word.
+    ///
+    /// # Returns:
+    ///
+    /// the biggest word, or none if the trie is empty
+    pub fn last(&self) -> Option<String> {
+        self.map.last().map(|(word, _)| word)
+    }
+
+    /// Searches for the smallest word strictly bigger than the given one.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to compare with (it does not have to be stored)
+    ///
+    /// # Returns:
+    ///
+    /// the next word, or none if no word is bigger
+    pub fn successor(&self, word: &str) -> Option<String> {
+        self.map.successor(word).map(|(word, _)| word)
+    }
+
+    /// Searches for the smallest word bigger than or equal to the given one.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to compare with (it does not have to be stored)
+    ///
+    /// # Returns:
+    ///
+    /// the word itself if stored or the next word, or none if no word is bigger
+    pub fn successor_or_equal(&self, word: &str) -> Option<String> {
+        self.map.successor_or_equal(word).map(|(word, _)| word)
+    }
+
+    /// Searches for the biggest word strictly smaller than the given one.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to compare with (it does not have to be stored)
+    ///
+    /// # Returns:
+    ///
+    /// the previous word, or none if no word is smaller
+    pub fn predecessor(&self, word: &str) -> Option<String> {
+        self.map.predecessor(word).map(|(word, _)| word)
+    }
+
+    /// Searches for the biggest word smaller than or equal to the given one.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to compare with (it does not have to be stored)
+    ///
+    /// # Returns:
+    ///
+    /// the word itself if stored or the previous word, or none if no word is smaller
+    pub fn predecessor_or_equal(&self, word: &str) -> Option<String> {
+        self.map.predecessor_or_equal(word).map(|(word, _)| word)
+    }
+
+    /// Counts the words smaller than the given word. If the word is stored,
+    /// that is its index in the lexicographic order.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to compare with (it does not have to be stored)
+    ///
+    /// # Returns:
+    ///
+    /// the amount of stored words smaller than the word
+    pub fn rank(&self, word: &str) -> usize {
+        self.map.rank(word)
+    }
+
+    /// Searches for the word at the given index in the lexicographic order.
+    ///
+    /// # Arguments:
+    ///
+    /// `index` - the index of the word to search for
+    ///
+    /// # Returns:
+    ///
+    /// the word, or none if the index is not smaller than the amount of words
+    pub fn select(&self, index: usize) -> Option<String> {
+        self.map.select(index).map(|(word, _)| word)
+    }
+
+    /// Lists the words within the given Levenshtein distance of the query,
+    /// sorted by distance then in lexicographic order.
+    ///
+    /// # Arguments:
+    ///
+    /// `query` - the word to compare the words with
+    /// `max_distance` - the maximum amount of edits between the query and the words
+    ///
+    /// # Returns:
+    ///
+    /// the found words with their distances
+    pub fn fuzzy_search(&self, query: &str, max_distance: usize) -> Vec<(String, usize)> {
+        self.fuzzy_search_with(query, max_distance, Distance::Levenshtein)
+    }
+
+    /// Lists the words within the given distance of the query,
+    /// sorted by distance then in lexicographic order.
+    ///
+    /// # Arguments:
+    ///
+    /// `query` - the word to compare the words with
+    /// `max_distance` - the maximum amount of edits between the query and the words
+    /// `distance` - the edit distance to use
+    ///
+    /// # Returns:
+    ///
+    /// the found words with their distances
+    pub fn fuzzy_search_with(
+        &self,
+        query: &str,
+        max_distance: usize,
+        distance: Distance,
+    ) -> Vec<(String, usize)> {
+
+        self.map
+            .fuzzy_search_with(query, max_distance, distance)
+            .into_iter()
+            .map(|(word, _, distance)| (word, distance))
+            .collect()
+    }
+
+    /// Lists the words matching the given pattern, in lexicographic order. The pattern may contain
+    /// `?` (any single character) and `*` (any run of characters, including an empty one).
+    ///
+    /// # Arguments:
+    ///
+    /// `pattern` - the pattern of the words to list
+    ///
+    /// # Returns:
+    ///
+    /// the matching words
+    pub fn matches(&self, pattern: &str) -> Vec<String> {
+        self.search_automaton(&Glob::new(pattern))
+    }
+
+    /// Lists the words accepted by the given automaton, in lexicographic order.
+    ///
+    /// # Arguments:
+    ///
+    /// `automaton` - the automaton accepting the words to list
+    ///
+    /// # Returns:
+    ///
+    /// the accepted words
+    pub fn search_automaton<A: Automaton>(&self, automaton: &A) -> Vec<String> {
+
+        self.map
+            .search_automaton(automaton)
+            .into_iter()
+            .map(|(word, _)| word)
+            .collect()
+    }
+
+    /// Lists the words starting with the given prefix, in lexicographic order. The iteration
+    /// stops as soon as the limit is reached, so the rest of the trie is not browsed.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the beginning of the words to list
+    /// `limit` - the maximum amount of words to list, if any
+    ///
+    /// # Returns:
+    ///
+    /// the words starting with the prefix
+    pub fn suggestions(&self, prefix: &str, limit: Option<usize>) -> Vec<String> {
+
+        let words = self.iter_prefix(prefix);
+
+        match limit {
+            Some(limit) => words.take(limit).collect(),
+            None => words.collect(),
+        }
+    }
+
+    /// Creates an iterator over all the words, in lexicographic order (same as `iter`).
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the words
+    pub fn keys(&self) -> Keys<'_, ()> {
+        self.map.keys()
+    }
+
+    /// Removes a word from the radix trie (may remove or merge nodes).
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to remove
+    ///
+    /// # Returns:
+    ///
+    /// true if the word was stored
+    pub fn remove(&mut self, word: &str) -> bool {
+        self.map.remove(word).is_some()
+    }
+
+    /// Creates the binary form of the radix trie, which can be stored and loaded
+    /// as a frozen radix trie without inserting the words again.
+    ///
+    /// # Returns:
+    ///
+    /// the versioned and checksummed binary form, or an error if a length or an offset
+    /// of the radix trie does not fit into the 32 bits integers of the format
+    pub fn to_bytes(&self) -> Result<Vec<u8>, FormatError> {
+        frozen::encode(&self.map)
+    }
+
+    /// Indicates if a word exists into the radix trie, either as a stored key
+    /// or as the beginning of a stored key (same as `has_prefix`)
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if the word exists, False if the word does not exist
+    pub fn exists(&self, word: &str) -> bool {
+        self.map.has_prefix(word)
+    }
+
+    /// Indicates if the given word has been inserted as a key into the radix trie.
+    /// Contrary to `exists`, the beginning of a stored key is not considered as contained.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the key to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if the key has been inserted, False if it has not
+    pub fn contains_key(&self, word: &str) -> bool {
+        self.map.contains_key(word)
+    }
+
+    /// Indicates if at least one stored key starts with the given prefix.
+    ///
+    /// # Arguments:
+    ///
+    /// `prefix` - the beginning of the keys to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if one key at least starts with the prefix, False otherwise
+    pub fn has_prefix(&self, prefix: &str) -> bool {
+        self.map.has_prefix(prefix)
+    }
+
+    /// Getter of the characters stored into the root node.
+    ///
+    /// # Returns:
+    ///
+    /// the characters into the root node
+    pub fn get_characters(&self) -> &str {
+        self.map.get_characters()
+    }
+
+    /// Getter of the children of the root node.
+    ///
+    /// # Returns:
+    ///
+    /// list of children
+    pub fn get_children(&self) -> &Vec<RadixTrieMap<()>> {
+        self.map.get_children()
+    }
+
+    /// Indicates if a stored key ends at the end of the root node characters (the empty word).
+    ///
+    /// # Returns:
+    ///
+    /// true if the root node terminates a key
+    pub fn is_terminal(&self) -> bool {
+        self.map.is_terminal()
+    }
+}
+
+impl<V, L: Label> Default for RadixTrieMap<V, L> {
+
+    fn default() -> RadixTrieMap<V, L> {
+        create_node(L::default().as_slice(), None)
+    }
+}
+
+impl<'a, V, L: Label + 'a> FromIterator<(L::Slice<'a>, V)> for RadixTrieMap<V, L> {
+
+    fn from_iter<I: IntoIterator<Item = (L::Slice<'a>, V)>>(iter: I) -> RadixTrieMap<V, L> {
+
+        let mut map = RadixTrieMap::default();
+        map.extend(iter);
+
+        map
+    }
+}
+
+impl<'a, V, L: Label + 'a> Extend<(L::Slice<'a>, V)> for RadixTrieMap<V, L> {
+
+    fn extend<I: IntoIterator<Item = (L::Slice<'a>, V)>>(&mut self, iter: I) {
+
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, V, L: Label> IntoIterator for &'a RadixTrieMap<V, L> {
+
+    type Item = (L, &'a V);
+    type IntoIter = Iter<'a, V, L>;
+
+    fn into_iter(self) -> Iter<'a, V, L> {
+        self.iter()
+    }
+}
+
+impl Default for RadixTrie {
+
+    fn default() -> RadixTrie {
+        RadixTrie::new()
+    }
+}
+
+impl<'a> FromIterator<&'a str> for RadixTrie {
+
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> RadixTrie {
+
+        let mut trie = RadixTrie::new();
+        trie.extend(iter);
+
+        trie
+    }
+}
+
+impl<'a> IntoIterator for &'a RadixTrie {
+
+    type Item = String;
+    type IntoIter = Keys<'a, ()>;
+
+    fn into_iter(self) -> Keys<'a, ()> {
+        self.iter()
+    }
+}
+
+impl<'a> Extend<&'a str> for RadixTrie {
+
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+
+        for word in iter {
+            self.insert(word);
+        }
+    }
+}
+
+impl<'a, V> Entry<'a, V> {
+
+    /// Getter of the key of the entry.
+    ///
+    /// # Returns:
+    ///
+    /// the key of the entry
+    pub fn key(&self) -> &str {
+
+        match *self {
+            Entry::Occupied(ref entry) => entry.key(),
+            Entry::Vacant(ref entry) => entry.key(),
+        }
+    }
+
+    /// Inserts the given value if the entry is vacant.
+    ///
+    /// # Arguments:
+    ///
+    /// `default` - the value to insert if the entry is vacant
+    ///
+    /// # Returns:
+    ///
+    /// the mutable value of the entry
+    pub fn or_insert(self, default: V) -> &'a mut V {
+
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Inserts the result of the given function if the entry is vacant.
+    ///
+    /// # Arguments:
+    ///
+    /// `default` - the function creating the value to insert if the entry is vacant
+    ///
+    /// # Returns:
+    ///
+    /// the mutable value of the entry
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Modifies the value of the entry with the given function if the entry is occupied.
+    ///
+    /// # Arguments:
+    ///
+    /// `function` - the function modifying the value
+    ///
+    /// # Returns:
+    ///
+    /// the entry itself
+    pub fn and_modify<F: FnOnce(&mut V)>(self, function: F) -> Entry<'a, V> {
+
+        match self {
+            Entry::Occupied(mut entry) => {
+                function(entry.get_mut());
+                Entry::Occupied(entry)
+            },
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, V: Default> Entry<'a, V> {
+
+    /// Inserts the default value if the entry is vacant.
+    ///
+    /// # Returns:
+    ///
+    /// the mutable value of the entry
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, V> OccupiedEntry<'a, V> {
+
+    /// Getter of the key of the entry.
+    ///
+    /// # Returns:
+    ///
+    /// the key of the entry
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Getter of the value of the entry.
+    ///
+    /// # Returns:
+    ///
+    /// the value of the entry
+    pub fn get(&self) -> &V {
+        self.value
+    }
+
+    /// Mutable getter of the value of the entry.
+    ///
+    /// # Returns:
+    ///
+    /// the mutable value of the entry
+    pub fn get_mut(&mut self) -> &mut V {
+        self.value
+    }
+
+    /// Converts the entry into a mutable reference to its value, bound to the map lifetime.
+    ///
+    /// # Returns:
+    ///
+    /// the mutable value of the entry
+    pub fn into_mut(self) -> &'a mut V {
+        self.value
+    }
+
+    /// Replaces the value of the entry.
+    ///
+    /// # Arguments:
+    ///
+    /// `value` - the new value
+    ///
+    /// # Returns:
+    ///
+    /// the previous value
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.value, value)
+    }
+}
+
+impl<'a, V> VacantEntry<'a, V> {
+
+    /// Getter of the key of the entry.
+    ///
+    /// # Returns:
+    ///
+    /// the key of the entry
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Inserts the key of the entry with the given value into the map.
+    ///
+    /// # Arguments:
+    ///
+    /// `value` - the value to insert
+    ///
+    /// # Returns:
+    ///
+    /// the mutable inserted value
+    pub fn insert(self, value: V) -> &'a mut V {
+
+        let map = self.map;
+        map.insert(&self.key, value);
+
+        map.get_mut(&self.key).unwrap()
+    }
+}
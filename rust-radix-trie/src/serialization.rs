@@ -0,0 +1,339 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::Deserialize;
+use serde::de::{
+    self,
+    Deserializer,
+    MapAccess,
+    SeqAccess,
+    Visitor,
+};
+use serde::ser::{
+    Serialize,
+    SerializeStruct,
+    Serializer,
+};
+
+use rt::{
+    self,
+    RadixTrie,
+    RadixTrieMap,
+};
+
+/// Wrapper serializing a radix trie or a radix trie map as its tree of nodes, instead of
+/// the flat list of its keys. Every node is a structure with its `characters`, its `children`,
+/// and either its `terminal` flag (radix trie) or its `value` (radix trie map).
+///
+/// The tree is checked when it is deserialized: only the root node has no characters,
+/// the children of a node start with different characters and are sorted, and every node
+/// without key has two children at least (otherwise it would have been merged with its child).
+pub struct NodeTree<T>(pub T);
+
+/// A node of a radix trie being serialized.
+struct WordNode<'a> {
+    characters: &'a str,
+    terminal: bool,
+    children: &'a [RadixTrieMap<()>],
+}
+
+/// A node of a radix trie map being serialized.
+struct ValueNode<'a, V: 'a> {
+    characters: &'a str,
+    value: Option<&'a V>,
+    children: &'a [RadixTrieMap<V>],
+}
+
+/// A node of a radix trie being deserialized, not checked yet.
+#[derive(Deserialize)]
+struct WordNodeTree {
+    characters: String,
+    #[serde(default)]
+    terminal: bool,
+    #[serde(default)]
+    children: Vec<WordNodeTree>,
+}
+
+/// A node of a radix trie map being deserialized, not checked yet.
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "V: Deserialize<'de>"))]
+struct ValueNodeTree<V> {
+    characters: String,
+    value: Option<V>,
+    #[serde(default)]
+    children: Vec<ValueNodeTree<V>>,
+}
+
+/// Visitor building a radix trie from a sequence of words.
+struct WordsVisitor;
+
+/// Visitor building a radix trie map from a map of keys and values.
+struct EntriesVisitor<V> {
+    values: PhantomData<V>,
+}
+
+impl<'a> WordNode<'a> {
+
+    /// Creates the serialized form of a node, which is not the root node.
+    ///
+    /// # Args:
+    ///
+    /// `node` - the node to serialize
+    ///
+    /// # Returns:
+    ///
+    /// the node to serialize
+    fn new(node: &'a RadixTrieMap<()>) -> WordNode<'a> {
+
+        WordNode {
+            characters: node.get_characters(),
+            terminal: node.is_terminal(),
+            children: node.get_children(),
+        }
+    }
+}
+
+impl<'a, V> ValueNode<'a, V> {
+
+    /// Creates the serialized form of a node.
+    ///
+    /// # Args:
+    ///
+    /// `node` - the node to serialize
+    ///
+    /// # Returns:
+    ///
+    /// the node to serialize
+    fn new(node: &'a RadixTrieMap<V>) -> ValueNode<'a, V> {
+
+        ValueNode {
+            characters: node.get_characters(),
+            value: node.get_value(),
+            children: node.get_children(),
+        }
+    }
+}
+
+impl WordNodeTree {
+
+    /// Converts the deserialized node and its children into radix trie map nodes,
+    /// where the terminal nodes have a value.
+    ///
+    /// # Returns:
+    ///
+    /// the converted node
+    fn into_value_node(self) -> ValueNodeTree<()> {
+
+        ValueNodeTree {
+            characters: self.characters,
+            value: if self.terminal { Some(()) } else { None },
+            children: self.children
+                .into_iter()
+                .map(WordNodeTree::into_value_node)
+                .collect(),
+        }
+    }
+}
+
+/// Recursively checks the deserialized nodes and creates the radix trie map nodes.
+///
+/// # Args:
+///
+/// `node` - the deserialized node
+/// `root` - true if the node is the root node of the trie
+///
+/// # Returns:
+///
+/// the radix trie map node, or an error if the node does not respect the radix trie invariants
+fn build_node<V, E: de::Error>(node: ValueNodeTree<V>, root: bool) -> Result<RadixTrieMap<V>, E> {
+
+    if root && !node.characters.is_empty() {
+        return Err(E::custom(format!("root node has characters {:?}", node.characters)));
+    }
+
+    if !root && node.characters.is_empty() {
+        return Err(E::custom("node without characters below the root node"));
+    }
+
+    if !root && node.value.is_none() && node.children.len() < 2 {
+        return Err(E::custom(format!(
+            "node {:?} has no value and less than two children",
+            node.characters,
+        )));
+    }
+
+    let children = node.children
+        .into_iter()
+        .map(|child| build_node(child, false))
+        .collect::<Result<Vec<RadixTrieMap<V>>, E>>()?;
+
+    let sorted = children.windows(2).all(|pair| {
+        pair[0].get_characters().chars().next() < pair[1].get_characters().chars().next()
+    });
+
+    if !sorted {
+        return Err(E::custom(format!(
+            "children of node {:?} are not sorted by distinct first characters",
+            node.characters,
+        )));
+    }
+
+    Ok(rt::create_node_with_children(node.characters, node.value, children))
+}
+
+impl Serialize for RadixTrie {
+
+    /// Serializes the words of the radix trie as a sequence, in lexicographic order.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de> Deserialize<'de> for RadixTrie {
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RadixTrie, D::Error> {
+        deserializer.deserialize_seq(WordsVisitor)
+    }
+}
+
+impl<V: Serialize> Serialize for RadixTrieMap<V> {
+
+    /// Serializes the keys and values of the radix trie map as a map, in lexicographic order of the keys.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de, V: Deserialize<'de>> Deserialize<'de> for RadixTrieMap<V> {
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RadixTrieMap<V>, D::Error> {
+        deserializer.deserialize_map(EntriesVisitor { values: PhantomData })
+    }
+}
+
+impl<'de> Visitor<'de> for WordsVisitor {
+
+    type Value = RadixTrie;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequence of words")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut sequence: A) -> Result<RadixTrie, A::Error> {
+
+        let mut trie = RadixTrie::new();
+
+        while let Some(word) = sequence.next_element::<String>()? {
+            trie.insert(&word);
+        }
+
+        Ok(trie)
+    }
+}
+
+impl<'de, V: Deserialize<'de>> Visitor<'de> for EntriesVisitor<V> {
+
+    type Value = RadixTrieMap<V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map of string keys")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut entries: A) -> Result<RadixTrieMap<V>, A::Error> {
+
+        let mut map = RadixTrieMap::new();
+
+        while let Some((key, value)) = entries.next_entry::<String, V>()? {
+            map.insert(&key, value);
+        }
+
+        Ok(map)
+    }
+}
+
+impl<'a> Serialize for WordNode<'a> {
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+
+        let mut node = serializer.serialize_struct("Node", 3)?;
+
+        node.serialize_field("characters", self.characters)?;
+        node.serialize_field("terminal", &self.terminal)?;
+        node.serialize_field("children", &self.children.iter().map(WordNode::new).collect::<Vec<WordNode<'_>>>())?;
+
+        node.end()
+    }
+}
+
+impl<'a, V: Serialize> Serialize for ValueNode<'a, V> {
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+
+        let mut node = serializer.serialize_struct("Node", 3)?;
+
+        node.serialize_field("characters", self.characters)?;
+        node.serialize_field("value", &self.value)?;
+        node.serialize_field("children", &self.children.iter().map(ValueNode::new).collect::<Vec<ValueNode<'_, V>>>())?;
+
+        node.end()
+    }
+}
+
+impl Serialize for NodeTree<&RadixTrie> {
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+
+        /* the root node of the trie is not public, but it has no characters
+           and it is terminal only if the empty word is stored */
+
+        let root = WordNode {
+            characters: "",
+            terminal: self.0.contains_key(""),
+            children: self.0.get_children(),
+        };
+
+        root.serialize(serializer)
+    }
+}
+
+impl Serialize for NodeTree<RadixTrie> {
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        NodeTree(&self.0).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NodeTree<RadixTrie> {
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<NodeTree<RadixTrie>, D::Error> {
+
+        let root = WordNodeTree::deserialize(deserializer)?;
+
+        build_node(root.into_value_node(), true)
+            .map(|map| NodeTree(RadixTrie::from_map(map)))
+    }
+}
+
+impl<V: Serialize> Serialize for NodeTree<&RadixTrieMap<V>> {
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ValueNode::new(self.0).serialize(serializer)
+    }
+}
+
+impl<V: Serialize> Serialize for NodeTree<RadixTrieMap<V>> {
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        NodeTree(&self.0).serialize(serializer)
+    }
+}
+
+impl<'de, V: Deserialize<'de>> Deserialize<'de> for NodeTree<RadixTrieMap<V>> {
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<NodeTree<RadixTrieMap<V>>, D::Error> {
+
+        let root = ValueNodeTree::deserialize(deserializer)?;
+
+        build_node(root, true).map(NodeTree)
+    }
+}
@@ -0,0 +1,236 @@
+use iter::{
+    Iter,
+    Keys,
+};
+use rt::RadixTrieMap;
+
+/// Read-only view over the words of a radix trie starting with a given prefix.
+/// Words are handled relatively to the prefix: the view contains the word "name"
+/// if the trie contains the word "user/42/name" and the prefix is "user/42/".
+/// The view keeps the node where the prefix ends, so the beginning of the trie
+/// is not browsed again for every query.
+pub struct SubTrie<'a> {
+    node: &'a RadixTrieMap<()>,
+    prefix: String,
+    offset: usize,
+}
+
+/// Mutable view over the words of a radix trie starting with a given prefix.
+/// Words are handled relatively to the prefix. Modifications are applied from the
+/// root of the trie, so the keys counts of the nodes above the prefix are kept up to date
+/// and these nodes can be pruned or merged if necessary; the prefix and the word
+/// are compared piece by piece, without being concatenated.
+pub struct SubTrieMut<'a> {
+    map: &'a mut RadixTrieMap<()>,
+    prefix: String,
+}
+
+impl<'a> SubTrie<'a> {
+
+    /// Creates a view over the keys of the given node.
+    ///
+    /// # Arguments:
+    ///
+    /// `node` - the node where the prefix ends
+    /// `prefix` - the prefix of the view
+    /// `offset` - the length of the prefix part stored into the parents of the node
+    ///
+    /// # Returns:
+    ///
+    /// new view
+    pub(crate) fn new(node: &'a RadixTrieMap<()>, prefix: &str, offset: usize) -> SubTrie<'a> {
+
+        SubTrie {
+            node,
+            prefix: prefix.to_string(),
+            offset,
+        }
+    }
+
+    /// Getter of the prefix of the view.
+    ///
+    /// # Returns:
+    ///
+    /// the prefix shared by all the words of the view
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Indicates if a word exists into the view, either as a stored word or as the beginning of a stored word.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to search for, relatively to the prefix
+    ///
+    /// # Returns:
+    ///
+    /// True if the word exists, False if the word does not exist
+    pub fn exists(&self, word: &str) -> bool {
+        self.node.has_prefix_pieces(self.stored(), word)
+    }
+
+    /// Indicates if the given word has been inserted into the view.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to search for, relatively to the prefix
+    ///
+    /// # Returns:
+    ///
+    /// True if the word has been inserted, False if it has not
+    pub fn contains_key(&self, word: &str) -> bool {
+        self.node.get_pieces(self.stored(), word).is_some()
+    }
+
+    /// Creates an iterator over the words of the view, relatively to the prefix, in lexicographic order.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the words
+    pub fn iter(&self) -> Keys<'a, ()> {
+
+        let iter = Iter::new(self.node, &self.prefix[..self.offset]);
+
+        Keys::new(iter.without_prefix(self.prefix.len()))
+    }
+
+    /// Counts the words of the view.
+    ///
+    /// # Returns:
+    ///
+    /// the amount of words starting with the prefix
+    pub fn len(&self) -> usize {
+        self.node.len()
+    }
+
+    /// Indicates if the view contains no word.
+    ///
+    /// # Returns:
+    ///
+    /// true if no word starts with the prefix
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Getter of the prefix part stored into the node characters, which is compared
+    /// with the node before the words relative to the prefix.
+    ///
+    /// # Returns:
+    ///
+    /// the end of the prefix
+    fn stored(&self) -> &str {
+        &self.prefix[self.offset..]
+    }
+}
+
+impl<'a> SubTrieMut<'a> {
+
+    /// Creates a mutable view over the words of the given trie starting with the given prefix.
+    ///
+    /// # Arguments:
+    ///
+    /// `map` - the root node of the trie to modify
+    /// `prefix` - the prefix of the view
+    ///
+    /// # Returns:
+    ///
+    /// new view
+    pub(crate) fn new(map: &'a mut RadixTrieMap<()>, prefix: &str) -> SubTrieMut<'a> {
+
+        SubTrieMut {
+            map,
+            prefix: prefix.to_string(),
+        }
+    }
+
+    /// Getter of the prefix of the view.
+    ///
+    /// # Returns:
+    ///
+    /// the prefix shared by all the words of the view
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Inserts a new word into the view.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the new word to store, relatively to the prefix
+    ///
+    /// # Returns:
+    ///
+    /// true if the word was not already stored
+    pub fn insert(&mut self, word: &str) -> bool {
+        self.map.insert_pieces(&self.prefix, word, ()).is_none()
+    }
+
+    /// Removes a word from the view.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to remove, relatively to the prefix
+    ///
+    /// # Returns:
+    ///
+    /// true if the word was stored
+    pub fn remove(&mut self, word: &str) -> bool {
+        self.map.remove_pieces(&self.prefix, word).is_some()
+    }
+
+    /// Indicates if a word exists into the view, either as a stored word or as the beginning of a stored word.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to search for, relatively to the prefix
+    ///
+    /// # Returns:
+    ///
+    /// True if the word exists, False if the word does not exist
+    pub fn exists(&self, word: &str) -> bool {
+        self.map.has_prefix_pieces(&self.prefix, word)
+    }
+
+    /// Indicates if the given word has been inserted into the view.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to search for, relatively to the prefix
+    ///
+    /// # Returns:
+    ///
+    /// True if the word has been inserted, False if it has not
+    pub fn contains_key(&self, word: &str) -> bool {
+        self.map.get_pieces(&self.prefix, word).is_some()
+    }
+
+    /// Creates an iterator over the words of the view, relatively to the prefix, in lexicographic order.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the words
+    pub fn iter(&self) -> Keys<'_, ()> {
+        Keys::new(self.map.iter_prefix(&self.prefix).without_prefix(self.prefix.len()))
+    }
+
+    /// Counts the words of the view.
+    ///
+    /// # Returns:
+    ///
+    /// the amount of words starting with the prefix
+    pub fn len(&self) -> usize {
+
+        self.map
+            .get_prefix_node(&self.prefix)
+            .map_or(0, |(node, _)| node.len())
+    }
+
+    /// Indicates if the view contains no word.
+    ///
+    /// # Returns:
+    ///
+    /// true if no word starts with the prefix
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::BTreeSet;
+use std::iter::{
+    self,
+    FromIterator,
+};
+use std::sync::Arc;
+
+use iter::Keys;
+use rt::{
+    RadixTrie,
+    RadixTrieMap,
+};
+
+/// A generalized suffix trie: every suffix of every inserted word is stored into a radix trie map,
+/// with references to the words ending with that suffix. As a fragment contained by a word is
+/// the beginning of one of its suffixes, the words containing the fragment are the words
+/// referenced by the suffixes starting with the fragment.
+#[derive(Clone, Default)]
+pub struct SuffixTrie {
+    words: RadixTrie,
+    suffixes: RadixTrieMap<BTreeSet<Arc<str>>>,
+}
+
+/// Lists the suffixes of the given word, from the whole word to the empty suffix.
+/// The suffixes always start on a character boundary.
+///
+/// # Args:
+///
+/// `word` - the word to split
+///
+/// # Returns:
+///
+/// iterator over the suffixes of the word
+fn suffixes(word: &str) -> impl Iterator<Item = &str> {
+
+    word.char_indices()
+        .map(|(index, _)| index)
+        .chain(iter::once(word.len()))
+        .map(move |index| &word[index..])
+}
+
+impl SuffixTrie {
+
+    /// Creates a new empty suffix trie.
+    ///
+    /// # Returns:
+    ///
+    /// new suffix trie
+    pub fn new() -> SuffixTrie {
+
+        SuffixTrie {
+            words: RadixTrie::new(),
+            suffixes: RadixTrieMap::new(),
+        }
+    }
+
+    /// Inserts a word and all its suffixes into the suffix trie.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to store
+    ///
+    /// # Returns:
+    ///
+    /// true if the word was not already stored
+    pub fn insert(&mut self, word: &str) -> bool {
+
+        if !self.words.insert(word) {
+            return false;
+        }
+
+        /* the word is shared by all its suffixes */
+
+        let reference: Arc<str> = Arc::from(word);
+
+        for suffix in suffixes(word) {
+            self.suffixes
+                .entry(suffix)
+                .or_default()
+                .insert(reference.clone());
+        }
+
+        true
+    }
+
+    /// Removes a word from the suffix trie. The suffixes which are not shared
+    /// with any other word are removed too.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to remove
+    ///
+    /// # Returns:
+    ///
+    /// true if the word was stored
+    pub fn remove(&mut self, word: &str) -> bool {
+
+        if !self.words.remove(word) {
+            return false;
+        }
+
+        for suffix in suffixes(word) {
+
+            let unused = match self.suffixes.get_mut(suffix) {
+                Some(words) => {
+                    words.remove(word);
+                    words.is_empty()
+                },
+                None => false,
+            };
+
+            if unused {
+                self.suffixes.remove(suffix);
+            }
+        }
+
+        true
+    }
+
+    /// Indicates if the given word has been inserted into the suffix trie.
+    ///
+    /// # Arguments:
+    ///
+    /// `word` - the word to search for
+    ///
+    /// # Returns:
+    ///
+    /// True if the word has been inserted, False if it has not
+    pub fn contains_key(&self, word: &str) -> bool {
+        self.words.contains_key(word)
+    }
+
+    /// Counts the words stored into the suffix trie (their suffixes are not counted).
+    ///
+    /// # Returns:
+    ///
+    /// the amount of stored words
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Indicates if the suffix trie contains no word.
+    ///
+    /// # Returns:
+    ///
+    /// true if no word is stored
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Creates an iterator over all the words, in lexicographic order.
+    ///
+    /// # Returns:
+    ///
+    /// iterator over the words
+    pub fn iter(&self) -> Keys<'_, ()> {
+        self.words.iter()
+    }
+
+    /// Lists the words containing the given fragment, in lexicographic order.
+    /// Only the suffixes starting with the fragment are browsed.
+    ///
+    /// # Arguments:
+    ///
+    /// `fragment` - the characters to search for into the words
+    ///
+    /// # Returns:
+    ///
+    /// the words containing the fragment
+    pub fn keys_containing(&self, fragment: &str) -> Vec<String> {
+
+        let words: BTreeSet<&Arc<str>> = self.suffixes
+            .values_prefix(fragment)
+            .flat_map(|words| words.iter())
+            .collect();
+
+        words.into_iter()
+            .map(|word| word.to_string())
+            .collect()
+    }
+
+    /// Lists the words ending with the given fragment, in lexicographic order.
+    ///
+    /// # Arguments:
+    ///
+    /// `fragment` - the end of the words to search for
+    ///
+    /// # Returns:
+    ///
+    /// the words ending with the fragment
+    pub fn keys_ending_with(&self, fragment: &str) -> Vec<String> {
+
+        match self.suffixes.get(fragment) {
+            Some(words) => words.iter().map(|word| word.to_string()).collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for SuffixTrie {
+
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> SuffixTrie {
+
+        let mut trie = SuffixTrie::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+impl<'a> Extend<&'a str> for SuffixTrie {
+
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for word in iter {
+            self.insert(word);
+        }
+    }
+}
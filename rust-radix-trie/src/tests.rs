@@ -1,12 +1,71 @@
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::module_inception)]
 mod tests {
 
-    use rt::RadixTrie;
+    use proptest::prelude::*;
+
+    use std::cell::Cell;
+    use std::collections::{
+        BTreeMap,
+        BTreeSet,
+    };
+    use std::iter::FromIterator;
+    use std::net::{
+        IpAddr,
+        Ipv4Addr,
+    };
+    use std::ops::Bound;
+    use std::path::PathBuf;
+
+    use automaton::Automaton;
+    use bits::{
+        BitSlice,
+        BitTrieMap,
+        Bits,
+    };
+    use builder::{
+        BuildError,
+        TrieBuilder,
+    };
+    use bytes::{
+        ByteTrie,
+        ByteTrieMap,
+    };
+    use frozen::{
+        write_u32,
+        FormatError,
+        FrozenRadixTrie,
+    };
+    use fuzzy::Distance;
+    use ip::{
+        IpPrefix,
+        IpPrefixTable,
+        PrefixError,
+    };
+    use map::TrieMap;
+    use regex::{
+        Regex,
+        RegexError,
+    };
+    use router::{
+        RouteError,
+        Router,
+    };
+    use rt::{
+        Entry,
+        RadixTrie,
+        RadixTrieMap,
+    };
+    #[cfg(feature = "serde")]
+    use serde_json;
+    #[cfg(feature = "serde")]
+    use serialization::NodeTree;
+    use suffix::SuffixTrie;
 
     #[test]
     fn test_node_creation_and_insertion() {
 
-        let mut node = RadixTrie::new("hello");
+        let mut node = RadixTrie::with_words(["hello"]);
 
         assert_eq!(node.exists("he"), true);
         assert_eq!(node.exists("hello"), true);
@@ -17,7 +76,12 @@ mod tests {
         node.insert(INSERTED_CHARACTERS);
 
         let children = node.get_children();
-        assert_eq!(children[0].get_characters(), INSERTED_CHARACTERS);
+        assert_eq!(children[0].get_characters(), "hello");
+        assert!(children[0].is_terminal());
+
+        let sub_children = children[0].get_children();
+        assert_eq!(sub_children[0].get_characters(), "world");
+        assert!(sub_children[0].is_terminal());
 
         assert_eq!(node.exists("he"), true);
         assert_eq!(node.exists("hello"), true);
@@ -26,7 +90,8 @@ mod tests {
 
         assert_eq!(node.exists("helloworldandmore"), false);
 
-        assert!(children[0].get_children().is_empty());
+        assert_eq!(children[0].get_children().len(), 1);
+        assert!(sub_children[0].get_children().is_empty());
     }
 
     #[test]
@@ -35,7 +100,7 @@ mod tests {
         const FIRST_CHARACTERS: &str = "hello";
         const SECOND_CHARACTERS: &str = "bonjour";
 
-        let mut node = RadixTrie::new(FIRST_CHARACTERS);
+        let mut node = RadixTrie::with_words([FIRST_CHARACTERS]);
 
         {
             let children = node.get_children();
@@ -50,11 +115,11 @@ mod tests {
         const ROOT_NODE_EXPECTED_CHARACTERS: &str = "";
         assert_eq!(node.get_characters(), ROOT_NODE_EXPECTED_CHARACTERS);
 
-        /* FIXME: order should not matter when getting the children */
+        /* children are sorted by their first character */
 
         let children = node.get_children();
-        assert_eq!(children[0].get_characters(), FIRST_CHARACTERS);
-        assert_eq!(children[1].get_characters(), SECOND_CHARACTERS);
+        assert_eq!(children[0].get_characters(), SECOND_CHARACTERS);
+        assert_eq!(children[1].get_characters(), FIRST_CHARACTERS);
 
         assert!(children[0].get_children().is_empty());
         assert!(children[1].get_children().is_empty());
@@ -66,7 +131,7 @@ mod tests {
         const FIRST_CHARACTERS: &str = "hello";
         const SECOND_CHARACTERS: &str = "bon";
 
-        let mut node = RadixTrie::new(FIRST_CHARACTERS);
+        let mut node = RadixTrie::with_words([FIRST_CHARACTERS]);
 
         {
             let children = node.get_children();
@@ -83,8 +148,8 @@ mod tests {
 
         let children = node.get_children();
         assert_eq!(node.get_characters(), "");
-        assert_eq!(children[0].get_characters(), FIRST_CHARACTERS);
-        assert_eq!(children[1].get_characters(), SECOND_CHARACTERS);
+        assert_eq!(children[0].get_characters(), SECOND_CHARACTERS);
+        assert_eq!(children[1].get_characters(), FIRST_CHARACTERS);
 
         assert!(children[0].get_children().is_empty());
         assert!(children[1].get_children().is_empty());
@@ -93,14 +158,14 @@ mod tests {
     #[test]
     fn test_get_children_from_root_has_one_child_by_default() {
 
-        let node = RadixTrie::new("hello");
+        let node = RadixTrie::with_words(["hello"]);
         assert_eq!(node.get_children().len(), 1);
     }
 
     #[test]
     fn test_characters_exist_into_root_node() {
 
-        let node = RadixTrie::new("hello");
+        let node = RadixTrie::with_words(["hello"]);
 
         assert_eq!(node.get_children().len(), 1);
 
@@ -115,7 +180,7 @@ mod tests {
     #[test]
     fn test_characters_exist_into_root_node_with_small_word() {
 
-        let node = RadixTrie::new("hello");
+        let node = RadixTrie::with_words(["hello"]);
 
         assert_eq!(node.get_children().len(), 1);
 
@@ -129,7 +194,7 @@ mod tests {
     #[test]
     fn test_characters_exist_into_root_node_with_long_word() {
 
-        let node = RadixTrie::new("hello");
+        let node = RadixTrie::with_words(["hello"]);
 
         assert_eq!(node.get_children().len(), 1);
 
@@ -143,7 +208,7 @@ mod tests {
     #[test]
     fn test_split_characters_at_the_beginning() {
 
-        let mut node = RadixTrie::new("bonjour");
+        let mut node = RadixTrie::with_words(["bonjour"]);
         node.insert("bien");
 
         let children = node.get_children();
@@ -153,8 +218,8 @@ mod tests {
 
         assert_eq!(sub_children.len(), 2);
 
-        assert_eq!(sub_children[0].get_characters(), "onjour");
-        assert_eq!(sub_children[1].get_characters(), "ien");
+        assert_eq!(sub_children[0].get_characters(), "ien");
+        assert_eq!(sub_children[1].get_characters(), "onjour");
 
         assert!(sub_children[0].get_children().is_empty());
         assert!(sub_children[1].get_children().is_empty());
@@ -163,15 +228,15 @@ mod tests {
     #[test]
     fn test_split_characters_in_the_middle() {
 
-        let mut node = RadixTrie::new("bonjour");
+        let mut node = RadixTrie::with_words(["bonjour"]);
         node.insert("bonapp");
 
         let children = node.get_children();
         let sub_children = children[0].get_children();
 
         assert_eq!(children[0].get_characters(), "bon");
-        assert_eq!(sub_children[0].get_characters(), "jour");
-        assert_eq!(sub_children[1].get_characters(), "app");
+        assert_eq!(sub_children[0].get_characters(), "app");
+        assert_eq!(sub_children[1].get_characters(), "jour");
 
         assert_eq!(sub_children.len(), 2);
         assert!(sub_children[0].get_children().is_empty());
@@ -181,7 +246,7 @@ mod tests {
     #[test]
     fn test_split_characters_at_the_end() {
 
-        let mut node = RadixTrie::new("bona");
+        let mut node = RadixTrie::with_words(["bona"]);
         node.insert("boni");
 
         let children = node.get_children();
@@ -199,7 +264,7 @@ mod tests {
     #[test]
     fn test_add_three_children_to_node() {
 
-        let mut node = RadixTrie::new("bonjour");
+        let mut node = RadixTrie::with_words(["bonjour"]);
         node.insert("bonapp");
         node.insert("bonsoir");
 
@@ -207,8 +272,8 @@ mod tests {
         let sub_children = children[0].get_children();
 
         assert_eq!(children[0].get_characters(), "bon");
-        assert_eq!(sub_children[0].get_characters(), "jour");
-        assert_eq!(sub_children[1].get_characters(), "app");
+        assert_eq!(sub_children[0].get_characters(), "app");
+        assert_eq!(sub_children[1].get_characters(), "jour");
         assert_eq!(sub_children[2].get_characters(), "soir");
 
         assert_eq!(node.exists("b"), true);
@@ -234,7 +299,7 @@ mod tests {
     #[test]
     fn test_add_four_children_to_node() {
 
-        let mut node = RadixTrie::new("bonjour");
+        let mut node = RadixTrie::with_words(["bonjour"]);
         node.insert("bonapp");
         node.insert("bonsoir");
         node.insert("bonnenuit");
@@ -243,10 +308,10 @@ mod tests {
         let sub_children = children[0].get_children();
 
         assert_eq!(children[0].get_characters(), "bon");
-        assert_eq!(sub_children[0].get_characters(), "jour");
-        assert_eq!(sub_children[1].get_characters(), "app");
-        assert_eq!(sub_children[2].get_characters(), "soir");
-        assert_eq!(sub_children[3].get_characters(), "nenuit");
+        assert_eq!(sub_children[0].get_characters(), "app");
+        assert_eq!(sub_children[1].get_characters(), "jour");
+        assert_eq!(sub_children[2].get_characters(), "nenuit");
+        assert_eq!(sub_children[3].get_characters(), "soir");
 
         assert_eq!(node.exists("bo"), true);
         assert_eq!(node.exists("bon"), true);
@@ -275,7 +340,7 @@ mod tests {
     #[test]
     fn test_add_three_children_to_node_with_different_root() {
 
-        let mut node = RadixTrie::new("jour");
+        let mut node = RadixTrie::with_words(["jour"]);
         node.insert("app");
         node.insert("soir");
 
@@ -284,8 +349,8 @@ mod tests {
 
         let children = node.get_children();
 
-        assert_eq!(children[0].get_characters(), "jour");
-        assert_eq!(children[1].get_characters(), "app");
+        assert_eq!(children[0].get_characters(), "app");
+        assert_eq!(children[1].get_characters(), "jour");
         assert_eq!(children[2].get_characters(), "soir");
 
         assert_eq!(node.exists("jour"), true);
@@ -300,7 +365,7 @@ mod tests {
     #[test]
     fn test_add_four_children_to_node_with_different_root() {
 
-        let mut node = RadixTrie::new("jour");
+        let mut node = RadixTrie::with_words(["jour"]);
         node.insert("app");
         node.insert("soir");
         node.insert("neapp");
@@ -310,10 +375,10 @@ mod tests {
 
         let children = node.get_children();
 
-        assert_eq!(children[0].get_characters(), "jour");
-        assert_eq!(children[1].get_characters(), "app");
-        assert_eq!(children[2].get_characters(), "soir");
-        assert_eq!(children[3].get_characters(), "neapp");
+        assert_eq!(children[0].get_characters(), "app");
+        assert_eq!(children[1].get_characters(), "jour");
+        assert_eq!(children[2].get_characters(), "neapp");
+        assert_eq!(children[3].get_characters(), "soir");
 
         assert_eq!(node.exists("jour"), true);
         assert_eq!(node.exists("app"), true);
@@ -329,7 +394,7 @@ mod tests {
     #[test]
     fn test_update_first_child_characters() {
 
-        let mut node = RadixTrie::new("salt");
+        let mut node = RadixTrie::with_words(["salt"]);
         node.insert("same");
 
         {
@@ -352,10 +417,11 @@ mod tests {
             let sub_children = children[0].get_children();
 
             assert_eq!(children[0].get_characters(), "sa");
-            assert_eq!(sub_children[0].get_characters(), "lted");
+            assert_eq!(sub_children[0].get_characters(), "lt");
+            assert_eq!(sub_children[0].get_children()[0].get_characters(), "ed");
 
             assert_eq!(children[0].get_children().len(), 2);
-            assert!(sub_children[0].get_children().is_empty());
+            assert_eq!(sub_children[0].get_children().len(), 1);
             assert!(sub_children[1].get_children().is_empty());
         }
 
@@ -380,7 +446,7 @@ mod tests {
     #[test]
     fn test_update_second_child_characters() {
 
-        let mut node = RadixTrie::new("salt");
+        let mut node = RadixTrie::with_words(["salt"]);
         node.insert("same");
 
         {
@@ -404,18 +470,19 @@ mod tests {
 
             assert_eq!(children[0].get_characters(), "sa");
             assert_eq!(sub_children[0].get_characters(), "lt");
-            assert_eq!(sub_children[1].get_characters(), "meless");
+            assert_eq!(sub_children[1].get_characters(), "me");
+            assert_eq!(sub_children[1].get_children()[0].get_characters(), "less");
 
             assert_eq!(children[0].get_children().len(), 2);
             assert!(sub_children[0].get_children().is_empty());
-            assert!(sub_children[1].get_children().is_empty());
+            assert_eq!(sub_children[1].get_children().len(), 1);
         }
     }
 
     #[test]
     fn test_characters_exist_into_root_node_when_single_character_children_exist() {
 
-        let mut node = RadixTrie::new("hello");
+        let mut node = RadixTrie::with_words(["hello"]);
         node.insert("hella");
 
         assert_eq!(node.exists("hello"), true);
@@ -433,8 +500,8 @@ mod tests {
         let sub_children = children[0].get_children();
 
         assert_eq!(children[0].get_characters(), "hell");
-        assert_eq!(sub_children[0].get_characters(), "o");
-        assert_eq!(sub_children[1].get_characters(), "a");
+        assert_eq!(sub_children[0].get_characters(), "a");
+        assert_eq!(sub_children[1].get_characters(), "o");
 
         assert_eq!(children[0].get_children().len(), 2);
         assert!(sub_children[0].get_children().is_empty());
@@ -444,7 +511,7 @@ mod tests {
     #[test]
     fn test_characters_exist_into_root_node_when_multiple_children_children_exist() {
 
-        let mut node = RadixTrie::new("helloworld");
+        let mut node = RadixTrie::with_words(["helloworld"]);
         node.insert("helloearth");
 
         assert_eq!(node.exists("hello"), true);
@@ -467,8 +534,8 @@ mod tests {
         let sub_children = children[0].get_children();
 
         assert_eq!(children[0].get_characters(), "hello");
-        assert_eq!(sub_children[0].get_characters(), "world");
-        assert_eq!(sub_children[1].get_characters(), "earth");
+        assert_eq!(sub_children[0].get_characters(), "earth");
+        assert_eq!(sub_children[1].get_characters(), "world");
 
         assert_eq!(children[0].get_children().len(), 2);
         assert!(sub_children[0].get_children().is_empty());
@@ -478,7 +545,7 @@ mod tests {
     #[test]
     fn test_create_two_subchildren() {
 
-        let mut node = RadixTrie::new("salt");
+        let mut node = RadixTrie::with_words(["salt"]);
         node.insert("same");
 
         {
@@ -501,11 +568,11 @@ mod tests {
             let sub_children = children[0].get_children();
 
             assert_eq!(children[0].get_characters(), "sa");
-            assert_eq!(sub_children[0].get_characters(), "lted");
+            assert_eq!(sub_children[0].get_characters(), "lt");
             assert_eq!(sub_children[1].get_characters(), "me");
 
             assert_eq!(sub_children.len(), 2);
-            assert!(sub_children[0].get_children().is_empty());
+            assert_eq!(sub_children[0].get_children().len(), 1);
             assert!(sub_children[1].get_children().is_empty());
         }
 
@@ -517,8 +584,8 @@ mod tests {
 
         assert_eq!(children[0].get_characters(), "sa");
         assert_eq!(sub_children[0].get_characters(), "lt");
-        assert_eq!(sub_sub_children[0].get_characters(), "ed");
-        assert_eq!(sub_sub_children[1].get_characters(), "andpepper");
+        assert_eq!(sub_sub_children[0].get_characters(), "andpepper");
+        assert_eq!(sub_sub_children[1].get_characters(), "ed");
 
         assert!(sub_children[1].get_children().is_empty());
         assert!(sub_sub_children[0].get_children().is_empty());
@@ -546,7 +613,7 @@ mod tests {
     #[test]
     fn test_create_three_subchildren() {
 
-        let mut node = RadixTrie::new("salt");
+        let mut node = RadixTrie::with_words(["salt"]);
         node.insert("same");
         node.insert("salted");
         node.insert("saltandpepper");
@@ -567,8 +634,8 @@ mod tests {
 
         let first_child_subchildren = sub_children[0].get_children();
 
-        assert_eq!(first_child_subchildren[0].get_characters(), "ed");
-        assert_eq!(first_child_subchildren[1].get_characters(), "andpepper");
+        assert_eq!(first_child_subchildren[0].get_characters(), "andpepper");
+        assert_eq!(first_child_subchildren[1].get_characters(), "ed");
         assert_eq!(first_child_subchildren[2].get_characters(), "o");
 
         assert!(first_child_subchildren[0].get_children().is_empty());
@@ -585,7 +652,7 @@ mod tests {
     #[test]
     fn test_create_two_subsubchildren() {
 
-        let mut node = RadixTrie::new("salt");
+        let mut node = RadixTrie::with_words(["salt"]);
         node.insert("same");
         node.insert("salted");
         node.insert("saltandpepper");
@@ -608,18 +675,18 @@ mod tests {
 
         let sub_children = sub_children[0].get_children();
 
-        assert_eq!(sub_children[0].get_characters(), "ed");
-        assert!(sub_children[0].get_children().is_empty());
+        assert_eq!(sub_children[0].get_characters(), "and");
+        assert_eq!(sub_children[0].get_children().len(), 2);
 
-        assert_eq!(sub_children[1].get_characters(), "and");
-        assert_eq!(sub_children[1].get_children().len(), 2);
+        assert_eq!(sub_children[1].get_characters(), "ed");
+        assert!(sub_children[1].get_children().is_empty());
 
-        let subsub_children = sub_children[1].get_children();
+        let subsub_children = sub_children[0].get_children();
 
-        assert_eq!(subsub_children[0].get_characters(), "pepper");
+        assert_eq!(subsub_children[0].get_characters(), "ketchup");
         assert!(subsub_children[0].get_children().is_empty());
 
-        assert_eq!(subsub_children[1].get_characters(), "ketchup");
+        assert_eq!(subsub_children[1].get_characters(), "pepper");
         assert!(subsub_children[1].get_children().is_empty());
 
         assert_eq!(node.exists("salt"), true);
@@ -633,7 +700,7 @@ mod tests {
     #[test]
     fn test_add_root_node_child_when_child_and_subchildren_exists() {
 
-        let mut node = RadixTrie::new("salt");
+        let mut node = RadixTrie::with_words(["salt"]);
         node.insert("same");
 
         assert_eq!(node.get_characters(), "");
@@ -662,11 +729,11 @@ mod tests {
         {
             let children = node.get_children();
 
-            assert_eq!(children[0].get_characters(), "sa");
-            assert_eq!(children[0].get_children().len(), 2);
+            assert_eq!(children[0].get_characters(), "hello");
+            assert!(children[0].get_children().is_empty());
 
-            assert_eq!(children[1].get_characters(), "hello");
-            assert!(children[1].get_children().is_empty());
+            assert_eq!(children[1].get_characters(), "sa");
+            assert_eq!(children[1].get_children().len(), 2);
         }
 
         node.insert("bonjour");
@@ -677,14 +744,14 @@ mod tests {
         {
             let children = node.get_children();
 
-            assert_eq!(children[0].get_characters(), "sa");
-            assert_eq!(children[0].get_children().len(), 2);
+            assert_eq!(children[0].get_characters(), "bonjour");
+            assert!(children[0].get_children().is_empty());
 
             assert_eq!(children[1].get_characters(), "hello");
             assert!(children[1].get_children().is_empty());
 
-            assert_eq!(children[2].get_characters(), "bonjour");
-            assert!(children[2].get_children().is_empty());
+            assert_eq!(children[2].get_characters(), "sa");
+            assert_eq!(children[2].get_children().len(), 2);
         }
 
         node.insert("hella");
@@ -695,21 +762,21 @@ mod tests {
         {
             let children = node.get_children();
 
-            assert_eq!(children[0].get_characters(), "sa");
-            assert_eq!(children[0].get_children().len(), 2);
+            assert_eq!(children[0].get_characters(), "bonjour");
+            assert!(children[0].get_children().is_empty());
 
             assert_eq!(children[1].get_characters(), "hell");
             assert_eq!(children[1].get_children().len(), 2);
 
-            assert_eq!(children[2].get_characters(), "bonjour");
-            assert!(children[2].get_children().is_empty());
+            assert_eq!(children[2].get_characters(), "sa");
+            assert_eq!(children[2].get_children().len(), 2);
 
             let sub_children = children[1].get_children();
 
-            assert_eq!(sub_children[0].get_characters(), "o");
+            assert_eq!(sub_children[0].get_characters(), "a");
             assert!(sub_children[0].get_children().is_empty());
 
-            assert_eq!(sub_children[1].get_characters(), "a");
+            assert_eq!(sub_children[1].get_characters(), "o");
             assert!(sub_children[1].get_children().is_empty());
         }
     }
@@ -717,7 +784,7 @@ mod tests {
     #[test]
     fn test_add_root_child_node_child_when_child_and_subchildren_exists() {
 
-        let mut node = RadixTrie::new("sa");
+        let mut node = RadixTrie::with_words(["sa"]);
         node.insert("hello");
         node.insert("same");
         node.insert("salt");
@@ -730,21 +797,21 @@ mod tests {
         {
             let children = node.get_children();
 
-            assert_eq!(children[0].get_characters(), "sa");
-            assert_eq!(children[0].get_children().len(), 2);
+            assert_eq!(children[0].get_characters(), "hello");
+            assert!(children[0].get_children().is_empty());
 
-            assert_eq!(children[1].get_characters(), "hello");
-            assert!(children[1].get_children().is_empty());
+            assert_eq!(children[1].get_characters(), "sa");
+            assert_eq!(children[1].get_children().len(), 2);
 
-            let sub_children = children[0].get_children();
+            let sub_children = children[1].get_children();
 
-            assert_eq!(sub_children[0].get_characters(), "me");
-            assert!(sub_children[0].get_children().is_empty());
+            assert_eq!(sub_children[0].get_characters(), "lt");
+            assert_eq!(sub_children[0].get_children().len(), 2);
 
-            assert_eq!(sub_children[1].get_characters(), "lt");
-            assert_eq!(sub_children[1].get_children().len(), 2);
+            assert_eq!(sub_children[1].get_characters(), "me");
+            assert!(sub_children[1].get_children().is_empty());
 
-            let sub_sub_children = sub_children[1].get_children();
+            let sub_sub_children = sub_children[0].get_children();
 
             assert_eq!(sub_sub_children[0].get_characters(), "andpepper");
             assert!(sub_sub_children[0].get_children().is_empty());
@@ -757,28 +824,28 @@ mod tests {
 
         let children = node.get_children();
 
-        assert_eq!(children[0].get_characters(), "sa");
-        assert_eq!(children[0].get_children().len(), 3);
+        assert_eq!(children[0].get_characters(), "hello");
+        assert!(children[0].get_children().is_empty());
 
-        assert_eq!(children[1].get_characters(), "hello");
-        assert!(children[1].get_children().is_empty());
+        assert_eq!(children[1].get_characters(), "sa");
+        assert_eq!(children[1].get_children().len(), 3);
 
-        let sub_children = children[0].get_children();
+        let sub_children = children[1].get_children();
 
-        assert_eq!(sub_children[0].get_characters(), "me");
+        assert_eq!(sub_children[0].get_characters(), "d");
         assert!(sub_children[0].get_children().is_empty());
 
         assert_eq!(sub_children[1].get_characters(), "lt");
         assert_eq!(sub_children[1].get_children().len(), 2);
 
-        assert_eq!(sub_children[2].get_characters(), "d");
+        assert_eq!(sub_children[2].get_characters(), "me");
         assert!(sub_children[2].get_children().is_empty());
     }
 
     #[test]
     fn test_replace_root_child_characters() {
 
-        let mut node = RadixTrie::new("salt");
+        let mut node = RadixTrie::with_words(["salt"]);
         node.insert("same");
 
         {
@@ -836,7 +903,7 @@ mod tests {
     #[test]
     fn test_replace_root_child_characters_and_move_sub_children() {
 
-        let mut node = RadixTrie::new("salt");
+        let mut node = RadixTrie::with_words(["salt"]);
         node.insert("same");
         node.insert("salted");
         node.insert("saltandpepper");
@@ -857,10 +924,10 @@ mod tests {
 
             let sub_sub_children = sub_children[0].get_children();
 
-            assert_eq!(sub_sub_children[0].get_characters(), "ed");
+            assert_eq!(sub_sub_children[0].get_characters(), "andpepper");
             assert!(sub_sub_children[0].get_children().is_empty());
 
-            assert_eq!(sub_sub_children[1].get_characters(), "andpepper");
+            assert_eq!(sub_sub_children[1].get_characters(), "ed");
             assert!(sub_sub_children[1].get_children().is_empty());
         }
 
@@ -890,11 +957,3031 @@ mod tests {
 
             let sub_sub_sub_children = sub_sub_children[0].get_children();
 
-            assert_eq!(sub_sub_sub_children[0].get_characters(), "ed");
+            assert_eq!(sub_sub_sub_children[0].get_characters(), "andpepper");
             assert!(sub_sub_sub_children[0].get_children().is_empty());
 
-            assert_eq!(sub_sub_sub_children[1].get_characters(), "andpepper");
+            assert_eq!(sub_sub_sub_children[1].get_characters(), "ed");
             assert!(sub_sub_sub_children[1].get_children().is_empty());
         }
     }
+
+    #[test]
+    fn test_contains_key_only_matches_inserted_keys() {
+
+        let mut node = RadixTrie::with_words(["salt"]);
+        node.insert("same");
+
+        assert!(node.contains_key("salt"));
+        assert!(node.contains_key("same"));
+
+        assert!(!node.contains_key("sa"));
+        assert!(!node.contains_key("sam"));
+        assert!(!node.contains_key("salted"));
+        assert!(!node.contains_key(""));
+
+        assert!(node.has_prefix("sa"));
+        assert!(node.has_prefix("sam"));
+        assert!(!node.has_prefix("salted"));
+    }
+
+    #[test]
+    fn test_insert_prefix_of_existing_key() {
+
+        let mut node = RadixTrie::with_words(["salted"]);
+        node.insert("salt");
+
+        let children = node.get_children();
+        let sub_children = children[0].get_children();
+
+        assert_eq!(children[0].get_characters(), "salt");
+        assert!(children[0].is_terminal());
+
+        assert_eq!(sub_children.len(), 1);
+        assert_eq!(sub_children[0].get_characters(), "ed");
+        assert!(sub_children[0].is_terminal());
+
+        assert!(node.contains_key("salt"));
+        assert!(node.contains_key("salted"));
+        assert!(!node.contains_key("salte"));
+    }
+
+    #[test]
+    fn test_insert_prefix_of_node_with_children() {
+
+        let mut node = RadixTrie::with_words(["salted"]);
+        node.insert("saltandpepper");
+        node.insert("sa");
+
+        let children = node.get_children();
+
+        assert_eq!(children[0].get_characters(), "sa");
+        assert!(children[0].is_terminal());
+        assert_eq!(children[0].get_children().len(), 1);
+
+        let sub_children = children[0].get_children();
+
+        assert_eq!(sub_children[0].get_characters(), "lt");
+        assert!(!sub_children[0].is_terminal());
+        assert_eq!(sub_children[0].get_children().len(), 2);
+
+        assert!(node.contains_key("sa"));
+        assert!(node.contains_key("salted"));
+        assert!(node.contains_key("saltandpepper"));
+        assert!(!node.contains_key("salt"));
+    }
+
+    #[test]
+    fn test_split_keeps_terminal_flag_on_moved_characters() {
+
+        let mut node = RadixTrie::with_words(["salt"]);
+        node.insert("salted");
+        node.insert("sol");
+
+        assert!(node.contains_key("salt"));
+        assert!(node.contains_key("salted"));
+        assert!(node.contains_key("sol"));
+
+        assert!(!node.contains_key("s"));
+        assert!(!node.contains_key("sa"));
+
+        let children = node.get_children();
+
+        assert_eq!(children[0].get_characters(), "s");
+        assert!(!children[0].is_terminal());
+
+        let sub_children = children[0].get_children();
+
+        assert_eq!(sub_children[0].get_characters(), "alt");
+        assert!(sub_children[0].is_terminal());
+        assert_eq!(sub_children[1].get_characters(), "ol");
+        assert!(sub_children[1].is_terminal());
+    }
+
+    #[test]
+    fn test_insert_into_node_with_mismatch_before_children() {
+
+        let mut node = RadixTrie::with_words(["salt"]);
+        node.insert("same");
+        node.insert("sml");
+
+        assert!(node.contains_key("salt"));
+        assert!(node.contains_key("same"));
+        assert!(node.contains_key("sml"));
+
+        assert!(!node.exists("saml"));
+    }
+
+    #[test]
+    fn test_map_insert_and_get_values() {
+
+        let mut map = RadixTrieMap::new();
+
+        assert_eq!(map.insert("salt", 1), None);
+        assert_eq!(map.insert("same", 2), None);
+        assert_eq!(map.insert("sa", 3), None);
+
+        assert_eq!(map.get("salt"), Some(&1));
+        assert_eq!(map.get("same"), Some(&2));
+        assert_eq!(map.get("sa"), Some(&3));
+
+        assert_eq!(map.get("s"), None);
+        assert_eq!(map.get("sal"), None);
+        assert_eq!(map.get("salted"), None);
+
+        assert_eq!(map.insert("salt", 4), Some(1));
+        assert_eq!(map.get("salt"), Some(&4));
+
+        let children = map.get_children();
+
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].get_characters(), "sa");
+        assert_eq!(children[0].get_value(), Some(&3));
+
+        let sub_children = children[0].get_children();
+
+        assert_eq!(sub_children[0].get_characters(), "lt");
+        assert_eq!(sub_children[0].get_value(), Some(&4));
+        assert_eq!(sub_children[1].get_characters(), "me");
+        assert_eq!(sub_children[1].get_value(), Some(&2));
+    }
+
+    #[test]
+    fn test_map_values_move_with_split_nodes() {
+
+        let mut map = RadixTrieMap::new();
+        map.insert("salt", "salt");
+        map.insert("salted", "salted");
+        map.insert("same", "same");
+        map.insert("sol", "sol");
+
+        assert_eq!(map.get("salt"), Some(&"salt"));
+        assert_eq!(map.get("salted"), Some(&"salted"));
+        assert_eq!(map.get("same"), Some(&"same"));
+        assert_eq!(map.get("sol"), Some(&"sol"));
+
+        assert_eq!(map.get("s"), None);
+        assert_eq!(map.get("sa"), None);
+    }
+
+    #[test]
+    fn test_map_get_mut() {
+
+        let mut map = RadixTrieMap::new();
+        map.insert("hello", 1);
+        map.insert("hella", 2);
+
+        *map.get_mut("hello").unwrap() += 10;
+
+        assert_eq!(map.get("hello"), Some(&11));
+        assert_eq!(map.get("hella"), Some(&2));
+        assert!(map.get_mut("hell").is_none());
+    }
+
+    #[test]
+    fn test_map_entry() {
+
+        let mut map = RadixTrieMap::new();
+
+        for word in &["salt", "same", "salt", "sa", "salt"] {
+            *map.entry(word).or_insert(0) += 1;
+        }
+
+        assert_eq!(map.get("salt"), Some(&3));
+        assert_eq!(map.get("same"), Some(&1));
+        assert_eq!(map.get("sa"), Some(&1));
+
+        map.entry("same").and_modify(|value| *value = 10).or_insert(0);
+        map.entry("sal").and_modify(|value| *value = 10).or_insert(5);
+
+        assert_eq!(map.get("same"), Some(&10));
+        assert_eq!(map.get("sal"), Some(&5));
+
+        match map.entry("salt") {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.key(), "salt");
+                assert_eq!(entry.insert(7), 3);
+            },
+            Entry::Vacant(_) => panic!("salt should be occupied"),
+        }
+
+        match map.entry("salted") {
+            Entry::Occupied(_) => panic!("salted should be vacant"),
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.key(), "salted");
+                assert_eq!(*entry.insert(8), 8);
+            },
+        }
+
+        assert_eq!(map.get("salt"), Some(&7));
+        assert_eq!(map.get("salted"), Some(&8));
+        assert_eq!(*map.entry("s").or_default(), 0);
+    }
+
+    #[test]
+    fn test_map_remove_value() {
+
+        let mut map = RadixTrieMap::new();
+        map.insert("salt", 1);
+        map.insert("same", 2);
+
+        assert_eq!(map.remove("sa"), None);
+        assert_eq!(map.remove("salted"), None);
+
+        assert_eq!(map.remove("salt"), Some(1));
+        assert_eq!(map.remove("salt"), None);
+
+        assert!(!map.contains_key("salt"));
+        assert!(map.contains_key("same"));
+    }
+
+    #[test]
+    fn test_set_insert_returns_if_word_is_new() {
+
+        let mut node = RadixTrie::with_words(["salt"]);
+
+        assert!(node.insert("same"));
+        assert!(node.insert("sa"));
+        assert!(!node.insert("salt"));
+        assert!(!node.insert("sa"));
+    }
+
+    #[test]
+    fn test_remove_leaf_merges_parent_with_remaining_child() {
+
+        let mut node = RadixTrie::with_words(["salt"]);
+        node.insert("same");
+
+        assert!(node.remove("salt"));
+
+        let children = node.get_children();
+
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].get_characters(), "same");
+        assert!(children[0].is_terminal());
+        assert!(children[0].get_children().is_empty());
+
+        assert!(!node.contains_key("salt"));
+        assert!(!node.exists("sal"));
+        assert!(node.contains_key("same"));
+    }
+
+    #[test]
+    fn test_remove_last_key_prunes_every_node() {
+
+        let mut node = RadixTrie::with_words(["salt"]);
+
+        assert!(node.remove("salt"));
+
+        assert!(node.get_children().is_empty());
+        assert!(!node.exists("s"));
+        assert!(!node.remove("salt"));
+    }
+
+    #[test]
+    fn test_remove_key_with_single_child_merges_nodes() {
+
+        let mut node = RadixTrie::with_words(["salt"]);
+        node.insert("salted");
+        node.insert("same");
+
+        assert!(node.remove("salt"));
+
+        let children = node.get_children();
+        let sub_children = children[0].get_children();
+
+        assert_eq!(children[0].get_characters(), "sa");
+        assert_eq!(sub_children.len(), 2);
+        assert_eq!(sub_children[0].get_characters(), "lted");
+        assert!(sub_children[0].is_terminal());
+        assert!(sub_children[0].get_children().is_empty());
+
+        assert!(node.contains_key("salted"));
+        assert!(node.contains_key("same"));
+        assert!(!node.contains_key("salt"));
+    }
+
+    #[test]
+    fn test_remove_key_with_several_children_keeps_node() {
+
+        let mut node = RadixTrie::with_words(["salt"]);
+        node.insert("salted");
+        node.insert("saltandpepper");
+
+        assert!(node.remove("salt"));
+
+        let children = node.get_children();
+
+        assert_eq!(children[0].get_characters(), "salt");
+        assert!(!children[0].is_terminal());
+        assert_eq!(children[0].get_children().len(), 2);
+
+        assert!(node.contains_key("salted"));
+        assert!(node.contains_key("saltandpepper"));
+        assert!(!node.contains_key("salt"));
+    }
+
+    #[test]
+    fn test_remove_cascades_merges_up_to_the_root() {
+
+        let mut node = RadixTrie::with_words(["salt"]);
+        node.insert("same");
+        node.insert("salted");
+        node.insert("saltandpepper");
+        node.insert("saltandketchup");
+
+        assert!(node.remove("saltandketchup"));
+        assert!(node.remove("salt"));
+
+        {
+            let children = node.get_children();
+            let sub_children = children[0].get_children();
+            let sub_sub_children = sub_children[0].get_children();
+
+            assert_eq!(sub_children[0].get_characters(), "lt");
+            assert_eq!(sub_sub_children[0].get_characters(), "andpepper");
+            assert_eq!(sub_sub_children[1].get_characters(), "ed");
+        }
+
+        assert!(node.remove("salted"));
+
+        {
+            let children = node.get_children();
+            let sub_children = children[0].get_children();
+
+            assert_eq!(children[0].get_characters(), "sa");
+            assert_eq!(sub_children[0].get_characters(), "ltandpepper");
+            assert_eq!(sub_children[1].get_characters(), "me");
+        }
+
+        assert!(node.remove("same"));
+
+        let children = node.get_children();
+
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].get_characters(), "saltandpepper");
+        assert!(children[0].get_children().is_empty());
+    }
+
+    #[test]
+    fn test_remove_missing_keys_does_not_modify_trie() {
+
+        let mut node = RadixTrie::with_words(["salt"]);
+        node.insert("same");
+
+        assert!(!node.remove("sa"));
+        assert!(!node.remove("sal"));
+        assert!(!node.remove("salted"));
+        assert!(!node.remove("hello"));
+
+        let children = node.get_children();
+
+        assert_eq!(children[0].get_characters(), "sa");
+        assert_eq!(children[0].get_children().len(), 2);
+    }
+
+    #[test]
+    fn test_map_remove_returns_value_and_merges() {
+
+        let mut map = RadixTrieMap::new();
+        map.insert("hello", 1);
+        map.insert("hella", 2);
+
+        assert_eq!(map.remove("hella"), Some(2));
+        assert_eq!(map.remove("hella"), None);
+
+        let children = map.get_children();
+
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].get_characters(), "hello");
+        assert_eq!(children[0].get_value(), Some(&1));
+    }
+
+    #[test]
+    fn test_insert_and_find_multibyte_keys() {
+
+        let mut node = RadixTrie::with_words(["café"]);
+        node.insert("cafés");
+        node.insert("caffè");
+        node.insert("日本");
+        node.insert("日本語");
+        node.insert("日曜");
+
+        assert!(node.contains_key("café"));
+        assert!(node.contains_key("cafés"));
+        assert!(node.contains_key("caffè"));
+        assert!(node.contains_key("日本"));
+        assert!(node.contains_key("日本語"));
+        assert!(node.contains_key("日曜"));
+
+        assert!(!node.contains_key("caf"));
+        assert!(!node.contains_key("日"));
+        assert!(!node.contains_key("日曜日"));
+
+        assert!(node.exists("caf"));
+        assert!(node.exists("日"));
+        assert!(node.exists("日本語"));
+        assert!(!node.exists("cafe"));
+        assert!(!node.exists("日月"));
+
+        let children = node.get_children();
+
+        assert_eq!(children[0].get_characters(), "caf");
+        assert_eq!(children[1].get_characters(), "日");
+
+        let sub_children = children[1].get_children();
+
+        assert_eq!(sub_children[0].get_characters(), "曜");
+        assert_eq!(sub_children[1].get_characters(), "本");
+    }
+
+    #[test]
+    fn test_split_between_characters_sharing_first_bytes() {
+
+        /* 'é' and 'è' are both encoded with two bytes starting with 0xC3 */
+
+        let mut node = RadixTrie::with_words(["é"]);
+        node.insert("è");
+        node.insert("xé");
+        node.insert("xè");
+
+        let children = node.get_children();
+
+        assert_eq!(children.len(), 3);
+        assert_eq!(children[0].get_characters(), "x");
+        assert_eq!(children[1].get_characters(), "è");
+        assert_eq!(children[2].get_characters(), "é");
+
+        let sub_children = children[0].get_children();
+
+        assert_eq!(sub_children[0].get_characters(), "è");
+        assert_eq!(sub_children[1].get_characters(), "é");
+
+        assert!(node.contains_key("é"));
+        assert!(node.contains_key("è"));
+        assert!(node.contains_key("xé"));
+        assert!(node.contains_key("xè"));
+
+        assert!(!node.exists("\u{c3}"));
+        assert!(!node.contains_key("x"));
+    }
+
+    #[test]
+    fn test_remove_multibyte_keys() {
+
+        let mut map = RadixTrieMap::new();
+        map.insert("naïve", 1);
+        map.insert("naïf", 2);
+        map.insert("ñandú", 3);
+
+        assert_eq!(map.remove("naïf"), Some(2));
+        assert_eq!(map.get("naïve"), Some(&1));
+
+        let children = map.get_children();
+
+        assert_eq!(children[0].get_characters(), "naïve");
+        assert_eq!(children[1].get_characters(), "ñandú");
+    }
+
+    #[test]
+    fn test_empty_word_is_a_key() {
+
+        let mut node = RadixTrie::with_words([""]);
+
+        assert!(node.is_terminal());
+        assert!(node.get_children().is_empty());
+        assert!(node.contains_key(""));
+        assert!(node.exists(""));
+        assert!(!node.exists("a"));
+
+        assert!(node.insert("a"));
+        assert!(!node.insert(""));
+
+        assert!(node.remove(""));
+        assert!(!node.contains_key(""));
+        assert!(node.exists(""));
+        assert!(node.contains_key("a"));
+
+        assert!(node.remove("a"));
+        assert!(!node.exists(""));
+    }
+
+    #[test]
+    fn test_words_shorter_than_node_characters() {
+
+        let mut node = RadixTrie::with_words(["hello"]);
+
+        assert!(!node.contains_key("h"));
+        assert!(!node.contains_key("hel"));
+        assert!(!node.remove("hel"));
+
+        node.insert("h");
+        node.insert("hel");
+
+        assert!(node.contains_key("h"));
+        assert!(node.contains_key("hel"));
+        assert!(node.contains_key("hello"));
+
+        let children = node.get_children();
+        let sub_children = children[0].get_children();
+
+        assert_eq!(children[0].get_characters(), "h");
+        assert_eq!(sub_children[0].get_characters(), "el");
+        assert_eq!(sub_children[0].get_children()[0].get_characters(), "lo");
+    }
+
+    #[test]
+    fn test_words_diverging_on_first_character() {
+
+        let mut node = RadixTrie::with_words(["a"]);
+        node.insert("b");
+        node.insert("ab");
+        node.insert("ba");
+
+        assert!(node.contains_key("a"));
+        assert!(node.contains_key("b"));
+        assert!(node.contains_key("ab"));
+        assert!(node.contains_key("ba"));
+
+        assert!(!node.contains_key("c"));
+        assert!(!node.contains_key("aa"));
+        assert!(!node.exists("bb"));
+        assert!(!node.remove("c"));
+    }
+
+    /// Browses the trie nodes in order to check the compressed-edge invariants: children have
+    /// characters and are sorted by their first character, which is different for every child,
+    /// and every child that terminates no key has at least two children.
+    ///
+    /// # Args:
+    ///
+    /// `children` - the children to check
+    fn check_children(children: &[RadixTrieMap<()>]) {
+
+        let first_characters: Vec<char> = children
+            .iter()
+            .map(|child| child.get_characters().chars().next().unwrap())
+            .collect();
+
+        assert!(first_characters.windows(2).all(|pair| pair[0] < pair[1]));
+
+        for child in children {
+
+            if !child.is_terminal() {
+                assert!(child.get_children().len() >= 2);
+            }
+
+            check_children(child.get_children());
+        }
+    }
+
+    /// Browses the trie nodes in order to collect all the stored keys.
+    ///
+    /// # Args:
+    ///
+    /// `node` - the node to browse
+    /// `prefix` - the characters of all the parents of the node
+    /// `keys` - the set where the keys are collected
+    fn collect_keys(node: &RadixTrieMap<()>, prefix: &str, keys: &mut BTreeSet<String>) {
+
+        let key = format!("{}{}", prefix, node.get_characters());
+
+        if node.is_terminal() {
+            keys.insert(key.clone());
+        }
+
+        for child in node.get_children() {
+            collect_keys(child, &key, keys);
+        }
+    }
+
+    proptest! {
+
+        #[test]
+        fn test_random_operations_against_btree_set(
+            operations in prop::collection::vec((any::<bool>(), "[aé日]{0,3}"), 0..64),
+            queries in prop::collection::vec("[abé日]{0,4}", 0..16),
+        ) {
+
+            let mut node = RadixTrie::new();
+            let mut model = BTreeSet::new();
+
+            for &(insertion, ref word) in operations.iter() {
+
+                if insertion {
+                    prop_assert_eq!(node.insert(word), model.insert(word.clone()));
+                } else {
+                    prop_assert_eq!(node.remove(word), model.remove(word));
+                }
+
+                check_children(node.get_children());
+                prop_assert_eq!(node.len(), model.len());
+            }
+
+            for word in queries.iter().chain(operations.iter().map(|(_, word)| word)) {
+
+                prop_assert_eq!(node.contains_key(word), model.contains(word));
+                prop_assert_eq!(
+                    node.exists(word),
+                    model.iter().any(|key| key.starts_with(word.as_str()))
+                );
+            }
+
+            let mut keys = BTreeSet::new();
+            for child in node.get_children() {
+                collect_keys(child, "", &mut keys);
+            }
+
+            if node.is_terminal() {
+                keys.insert(String::new());
+            }
+
+            prop_assert_eq!(&keys, &model);
+
+            prop_assert_eq!(
+                node.iter().collect::<Vec<String>>(),
+                model.iter().cloned().collect::<Vec<String>>()
+            );
+
+            for (start, end) in queries.iter().zip(queries.iter().skip(1)) {
+
+                if start > end {
+                    continue;
+                }
+
+                let bounds = (Bound::Included(start.as_str()), Bound::Excluded(end.as_str()));
+
+                prop_assert_eq!(
+                    node.range(bounds).collect::<Vec<String>>(),
+                    model.range::<str, _>(bounds).cloned().collect::<Vec<String>>()
+                );
+
+                let bounds = (Bound::Excluded(start.as_str()), Bound::Included(end.as_str()));
+
+                prop_assert_eq!(
+                    node.range(bounds).collect::<Vec<String>>(),
+                    model.range::<str, _>(bounds).cloned().collect::<Vec<String>>()
+                );
+            }
+
+            for (index, key) in model.iter().enumerate() {
+                prop_assert_eq!(node.rank(key), index);
+                prop_assert_eq!(node.select(index), Some(key.clone()));
+            }
+
+            prop_assert_eq!(node.select(model.len()), None);
+
+            for word in queries.iter() {
+                prop_assert_eq!(
+                    node.rank(word),
+                    model.range::<str, _>((Bound::Unbounded, Bound::Excluded(word.as_str()))).count()
+                );
+            }
+
+            prop_assert_eq!(node.first(), model.iter().next().cloned());
+            prop_assert_eq!(node.last(), model.iter().next_back().cloned());
+
+            for word in queries.iter() {
+
+                let key = word.as_str();
+
+                prop_assert_eq!(
+                    node.successor(key),
+                    model.range::<str, _>((Bound::Excluded(key), Bound::Unbounded)).next().cloned()
+                );
+                prop_assert_eq!(
+                    node.successor_or_equal(key),
+                    model.range::<str, _>((Bound::Included(key), Bound::Unbounded)).next().cloned()
+                );
+                prop_assert_eq!(
+                    node.predecessor(key),
+                    model.range::<str, _>((Bound::Unbounded, Bound::Excluded(key))).next_back().cloned()
+                );
+                prop_assert_eq!(
+                    node.predecessor_or_equal(key),
+                    model.range::<str, _>((Bound::Unbounded, Bound::Included(key))).next_back().cloned()
+                );
+            }
+
+            for prefix in queries.iter() {
+
+                let longest_prefix = model
+                    .iter()
+                    .filter(|key| prefix.starts_with(key.as_str()))
+                    .max_by_key(|key| key.len());
+
+                prop_assert_eq!(node.longest_prefix(prefix), longest_prefix.map(|key| key.as_str()));
+
+                let keys: Vec<String> = model
+                    .iter()
+                    .filter(|key| key.starts_with(prefix.as_str()))
+                    .cloned()
+                    .collect();
+
+                prop_assert_eq!(node.iter_prefix(prefix).collect::<Vec<String>>(), keys);
+            }
+        }
+    }
+
+    #[test]
+    fn test_new_radix_trie_is_empty() {
+
+        let node = RadixTrie::new();
+
+        assert!(node.is_empty());
+        assert_eq!(node.len(), 0);
+        assert!(node.get_children().is_empty());
+        assert!(!node.exists(""));
+        assert!(!node.contains_key(""));
+
+        let node: RadixTrie = Default::default();
+        assert!(node.is_empty());
+
+        let map: RadixTrieMap<usize> = Default::default();
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_len_follows_insertions_and_removals() {
+
+        let mut node = RadixTrie::new();
+
+        node.insert("salt");
+        node.insert("same");
+        node.insert("sa");
+        node.insert("salt");
+
+        assert_eq!(node.len(), 3);
+        assert!(!node.is_empty());
+
+        node.remove("sa");
+        node.remove("hello");
+
+        assert_eq!(node.len(), 2);
+
+        node.remove("salt");
+        node.remove("same");
+
+        assert_eq!(node.len(), 0);
+        assert!(node.is_empty());
+    }
+
+    #[test]
+    fn test_clear_removes_all_words() {
+
+        let mut node = RadixTrie::with_words(["salt", "same", ""]);
+
+        assert_eq!(node.len(), 3);
+
+        node.clear();
+
+        assert!(node.is_empty());
+        assert!(!node.contains_key(""));
+        assert!(!node.exists("s"));
+
+        node.insert("hello");
+        assert_eq!(node.len(), 1);
+    }
+
+    #[test]
+    fn test_collect_and_extend_words() {
+
+        let mut node: RadixTrie = vec!["salt", "same"].into_iter().collect();
+        node.extend(vec!["salted", "same"]);
+
+        assert_eq!(node.len(), 3);
+        assert!(node.contains_key("salt"));
+        assert!(node.contains_key("same"));
+        assert!(node.contains_key("salted"));
+
+        let words = ["hello", "hella"];
+        let node = RadixTrie::with_words(words.iter().cloned());
+
+        assert_eq!(node.len(), 2);
+        assert_eq!(node.get_children()[0].get_characters(), "hell");
+    }
+
+    #[test]
+    fn test_collect_and_extend_map() {
+
+        let mut map: RadixTrieMap<usize> = vec![("salt", 1), ("same", 2)].into_iter().collect();
+        map.extend(vec![("salt", 3), ("sa", 4)]);
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get("salt"), Some(&3));
+        assert_eq!(map.get("same"), Some(&2));
+        assert_eq!(map.get("sa"), Some(&4));
+
+        map.clear();
+
+        assert!(map.is_empty());
+        assert_eq!(map.get("salt"), None);
+    }
+
+    #[test]
+    fn test_children_are_sorted_by_first_character() {
+
+        let node = RadixTrie::with_words(["soir", "jour", "app", "neapp", "bon"]);
+
+        let characters: Vec<&str> = node.get_children()
+            .iter()
+            .map(|child| child.get_characters())
+            .collect();
+
+        assert_eq!(characters, vec!["app", "bon", "jour", "neapp", "soir"]);
+    }
+
+    #[test]
+    fn test_iterate_over_words_in_lexicographic_order() {
+
+        let node = RadixTrie::with_words([
+            "salted",
+            "same",
+            "sa",
+            "saltandpepper",
+            "hello",
+            "salt",
+            "",
+            "日本",
+            "hella",
+        ]);
+
+        let words: Vec<String> = node.iter().collect();
+
+        assert_eq!(
+            words,
+            vec![
+                "",
+                "hella",
+                "hello",
+                "sa",
+                "salt",
+                "saltandpepper",
+                "salted",
+                "same",
+                "日本",
+            ]
+        );
+
+        assert_eq!(node.keys().collect::<Vec<String>>(), words);
+        assert_eq!((&node).into_iter().count(), 9);
+
+        let mut count = 0;
+        for word in &node {
+            assert!(node.contains_key(&word));
+            count += 1;
+        }
+
+        assert_eq!(count, node.len());
+    }
+
+    #[test]
+    fn test_iterate_over_empty_trie() {
+
+        let node = RadixTrie::new();
+        assert_eq!(node.iter().next(), None);
+
+        let map: RadixTrieMap<usize> = RadixTrieMap::new();
+        assert_eq!(map.iter().next(), None);
+    }
+
+    #[test]
+    fn test_iterate_over_map_keys_and_values() {
+
+        let map: RadixTrieMap<usize> = vec![
+            ("salt", 1),
+            ("same", 2),
+            ("sa", 3),
+            ("bonjour", 4),
+        ].into_iter().collect();
+
+        let entries: Vec<(String, &usize)> = map.iter().collect();
+
+        assert_eq!(
+            entries,
+            vec![
+                ("bonjour".to_string(), &4),
+                ("sa".to_string(), &3),
+                ("salt".to_string(), &1),
+                ("same".to_string(), &2),
+            ]
+        );
+
+        assert_eq!(map.keys().collect::<Vec<String>>(), vec!["bonjour", "sa", "salt", "same"]);
+        assert_eq!(map.values().cloned().collect::<Vec<usize>>(), vec![4, 3, 1, 2]);
+        assert_eq!(map.values_prefix("sa").cloned().collect::<Vec<usize>>(), vec![3, 1, 2]);
+        assert_eq!(map.values_prefix("sal").cloned().collect::<Vec<usize>>(), vec![1]);
+        assert_eq!(map.values_prefix("x").count(), 0);
+
+        let sum: usize = (&map).into_iter().map(|(_, value)| *value).sum();
+        assert_eq!(sum, 10);
+    }
+
+    #[test]
+    fn test_iterate_over_words_with_prefix() {
+
+        let node = RadixTrie::with_words([
+            "salt",
+            "same",
+            "salted",
+            "saltandpepper",
+            "sol",
+            "hello",
+        ]);
+
+        assert_eq!(
+            node.iter_prefix("sa").collect::<Vec<String>>(),
+            vec!["salt", "saltandpepper", "salted", "same"]
+        );
+
+        assert_eq!(
+            node.iter_prefix("salt").collect::<Vec<String>>(),
+            vec!["salt", "saltandpepper", "salted"]
+        );
+
+        assert_eq!(
+            node.iter_prefix("s").collect::<Vec<String>>(),
+            vec!["salt", "saltandpepper", "salted", "same", "sol"]
+        );
+
+        assert_eq!(node.iter_prefix("").count(), 6);
+        assert_eq!(node.iter_prefix("saltandpepper").collect::<Vec<String>>(), vec!["saltandpepper"]);
+
+        assert_eq!(node.iter_prefix("saltandpeppers").next(), None);
+        assert_eq!(node.iter_prefix("sali").next(), None);
+        assert_eq!(node.iter_prefix("x").next(), None);
+    }
+
+    #[test]
+    fn test_iterate_over_words_with_prefix_ending_inside_node() {
+
+        let node = RadixTrie::with_words(["saltandpepper", "saltandketchup", "café", "cafés"]);
+
+        assert_eq!(
+            node.iter_prefix("saltan").collect::<Vec<String>>(),
+            vec!["saltandketchup", "saltandpepper"]
+        );
+
+        assert_eq!(
+            node.iter_prefix("saltandp").collect::<Vec<String>>(),
+            vec!["saltandpepper"]
+        );
+
+        assert_eq!(
+            node.iter_prefix("caf").collect::<Vec<String>>(),
+            vec!["café", "cafés"]
+        );
+
+        assert_eq!(node.iter_prefix("saltandpa").next(), None);
+        assert_eq!(node.iter_prefix("cafe").next(), None);
+    }
+
+    #[test]
+    fn test_suggestions_with_limit() {
+
+        let node = RadixTrie::with_words(["salt", "same", "salted", "saltandpepper", "sol"]);
+
+        assert_eq!(node.suggestions("sa", Some(2)), vec!["salt", "saltandpepper"]);
+        assert_eq!(node.suggestions("sa", Some(0)), Vec::<String>::new());
+        assert_eq!(node.suggestions("so", Some(10)), vec!["sol"]);
+        assert_eq!(node.suggestions("sa", None).len(), 4);
+        assert!(node.suggestions("b", None).is_empty());
+    }
+
+    #[test]
+    fn test_map_iterate_over_keys_with_prefix() {
+
+        let map: RadixTrieMap<usize> = vec![
+            ("user/42/name", 1),
+            ("user/42/email", 2),
+            ("user/43/name", 3),
+        ].into_iter().collect();
+
+        let entries: Vec<(String, &usize)> = map.iter_prefix("user/42/").collect();
+
+        assert_eq!(
+            entries,
+            vec![
+                ("user/42/email".to_string(), &2),
+                ("user/42/name".to_string(), &1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_longest_prefix() {
+
+        let node = RadixTrie::with_words(["/", "/api", "/api/v1", "/api/v1/users", "/static"]);
+
+        assert_eq!(node.longest_prefix("/api/v1/users/42"), Some("/api/v1/users"));
+        assert_eq!(node.longest_prefix("/api/v1/user"), Some("/api/v1"));
+        assert_eq!(node.longest_prefix("/api/v2"), Some("/api"));
+        assert_eq!(node.longest_prefix("/api"), Some("/api"));
+        assert_eq!(node.longest_prefix("/ap"), Some("/"));
+        assert_eq!(node.longest_prefix("/statics"), Some("/static"));
+        assert_eq!(node.longest_prefix("api"), None);
+        assert_eq!(node.longest_prefix(""), None);
+    }
+
+    #[test]
+    fn test_longest_prefix_with_empty_word_and_multibyte_keys() {
+
+        let mut node = RadixTrie::with_words(["日本", "日本語"]);
+
+        assert_eq!(node.longest_prefix("日本語学校"), Some("日本語"));
+        assert_eq!(node.longest_prefix("日本人"), Some("日本"));
+        assert_eq!(node.longest_prefix("日曜"), None);
+
+        node.insert("");
+
+        assert_eq!(node.longest_prefix("日曜"), Some(""));
+        assert_eq!(node.longest_prefix(""), Some(""));
+    }
+
+    #[test]
+    fn test_map_longest_prefix() {
+
+        let map: RadixTrieMap<&str> = vec![
+            ("10.", "private"),
+            ("10.1.", "office"),
+            ("192.168.", "home"),
+        ].into_iter().collect();
+
+        assert_eq!(map.longest_prefix("10.1.2.3"), Some(("10.1.", &"office")));
+        assert_eq!(map.longest_prefix("10.2.2.3"), Some(("10.", &"private")));
+        assert_eq!(map.longest_prefix("192.168.1.1"), Some(("192.168.", &"home")));
+        assert_eq!(map.longest_prefix("172.16.0.1"), None);
+    }
+
+    #[test]
+    fn test_subtrie_queries_relative_to_prefix() {
+
+        let node = RadixTrie::with_words([
+            "user/42/name",
+            "user/42/email",
+            "user/42/",
+            "user/43/name",
+            "group/1",
+        ]);
+
+        let subtrie = node.subtrie("user/42/").unwrap();
+
+        assert_eq!(subtrie.prefix(), "user/42/");
+        assert_eq!(subtrie.len(), 3);
+        assert!(!subtrie.is_empty());
+
+        assert!(subtrie.contains_key("name"));
+        assert!(subtrie.contains_key("email"));
+        assert!(subtrie.contains_key(""));
+        assert!(!subtrie.contains_key("nam"));
+        assert!(!subtrie.contains_key("user/42/name"));
+
+        assert!(subtrie.exists("nam"));
+        assert!(subtrie.exists(""));
+        assert!(!subtrie.exists("names"));
+
+        assert_eq!(subtrie.iter().collect::<Vec<String>>(), vec!["", "email", "name"]);
+    }
+
+    #[test]
+    fn test_subtrie_with_prefix_ending_inside_node() {
+
+        let node = RadixTrie::with_words(["saltandpepper", "saltandketchup", "salted", "same"]);
+
+        let subtrie = node.subtrie("saltan").unwrap();
+
+        assert_eq!(subtrie.len(), 2);
+        assert!(subtrie.contains_key("dpepper"));
+        assert!(subtrie.contains_key("dketchup"));
+        assert!(!subtrie.contains_key("pepper"));
+        assert!(subtrie.exists("d"));
+        assert!(!subtrie.exists("e"));
+
+        assert_eq!(subtrie.iter().collect::<Vec<String>>(), vec!["dketchup", "dpepper"]);
+
+        let subtrie = node.subtrie("sa").unwrap();
+
+        assert_eq!(subtrie.len(), 4);
+        assert_eq!(
+            subtrie.iter().collect::<Vec<String>>(),
+            vec!["ltandketchup", "ltandpepper", "lted", "me"]
+        );
+
+        assert!(node.subtrie("saltandpa").is_none());
+        assert!(node.subtrie("x").is_none());
+        assert!(RadixTrie::new().subtrie("").is_none());
+        assert_eq!(node.subtrie("").unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_subtrie_mut_modifies_words_with_prefix() {
+
+        let mut node = RadixTrie::with_words(["user/42/name", "user/43/name"]);
+
+        {
+            let mut subtrie = node.subtrie_mut("user/42/");
+
+            assert!(subtrie.insert("email"));
+            assert!(!subtrie.insert("name"));
+
+            assert_eq!(subtrie.len(), 2);
+            assert!(subtrie.contains_key("email"));
+            assert!(subtrie.exists("em"));
+            assert_eq!(subtrie.iter().collect::<Vec<String>>(), vec!["email", "name"]);
+
+            assert!(subtrie.remove("name"));
+            assert!(subtrie.remove("email"));
+            assert!(!subtrie.remove("email"));
+
+            assert!(subtrie.is_empty());
+            assert_eq!(subtrie.len(), 0);
+        }
+
+        assert!(node.contains_key("user/43/name"));
+        assert_eq!(node.len(), 1);
+
+        let children = node.get_children();
+
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].get_characters(), "user/43/name");
+
+        assert!(node.subtrie_mut("user/42/").is_empty());
+    }
+
+    #[test]
+    fn test_subtrie_mut_fills_new_namespace() {
+
+        let mut node = RadixTrie::with_words(["user/42/name", "user/4"]);
+
+        {
+            let mut subtrie = node.subtrie_mut("user/43/");
+
+            assert!(subtrie.is_empty());
+            assert!(!subtrie.exists(""));
+
+            assert!(subtrie.insert("name"));
+            assert!(subtrie.insert(""));
+            assert!(!subtrie.insert("name"));
+
+            assert_eq!(subtrie.len(), 2);
+            assert!(subtrie.contains_key(""));
+            assert!(subtrie.exists("na"));
+            assert!(!subtrie.contains_key("na"));
+            assert_eq!(subtrie.iter().collect::<Vec<String>>(), vec!["", "name"]);
+        }
+
+        assert_eq!(node.len(), 4);
+        assert!(node.contains_key("user/43/name"));
+        assert!(node.contains_key("user/43/"));
+
+        /* the prefix ends inside the node characters of "user/4" */
+
+        let mut subtrie = node.subtrie_mut("user/");
+
+        assert_eq!(subtrie.len(), 4);
+        assert!(subtrie.contains_key("4"));
+        assert!(subtrie.remove("4"));
+        assert!(!subtrie.contains_key("4"));
+        assert!(subtrie.exists("4"));
+        assert_eq!(node.len(), 3);
+    }
+
+    #[test]
+    fn test_range_over_words() {
+
+        let node = RadixTrie::with_words([
+            "apple",
+            "banana",
+            "band",
+            "bandana",
+            "can",
+            "candy",
+            "dog",
+        ]);
+
+        assert_eq!(
+            node.range("b".."c").collect::<Vec<String>>(),
+            vec!["banana", "band", "bandana"]
+        );
+
+        assert_eq!(
+            node.range("band"..="can").collect::<Vec<String>>(),
+            vec!["band", "bandana", "can"]
+        );
+
+        assert_eq!(
+            node.range("band".."can").collect::<Vec<String>>(),
+            vec!["band", "bandana"]
+        );
+
+        assert_eq!(
+            node.range("bandb"..).collect::<Vec<String>>(),
+            vec!["can", "candy", "dog"]
+        );
+
+        assert_eq!(
+            node.range(.."band").collect::<Vec<String>>(),
+            vec!["apple", "banana"]
+        );
+
+        assert_eq!(node.range(..).count(), 7);
+        assert_eq!(node.range("e"..).next(), None);
+        assert_eq!(node.range(.."a").next(), None);
+        assert_eq!(node.range("c".."b").next(), None);
+    }
+
+    #[test]
+    fn test_range_with_excluded_start() {
+
+        let node = RadixTrie::with_words(["can", "candy", "cane", "dog"]);
+
+        let range = (Bound::Excluded("can"), Bound::Included("cane"));
+
+        assert_eq!(node.range(range).collect::<Vec<String>>(), vec!["candy", "cane"]);
+    }
+
+    #[test]
+    fn test_map_range_over_keys_and_values() {
+
+        let map: RadixTrieMap<usize> = vec![
+            ("job-001", 1),
+            ("job-002", 2),
+            ("job-010", 10),
+            ("job-100", 100),
+        ].into_iter().collect();
+
+        let entries: Vec<(String, &usize)> = map.range("job-002".."job-100").collect();
+
+        assert_eq!(
+            entries,
+            vec![
+                ("job-002".to_string(), &2),
+                ("job-010".to_string(), &10),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_first_and_last() {
+
+        let node = RadixTrie::with_words(["job-010", "job-002", "job-1", "job-002a"]);
+
+        assert_eq!(node.first(), Some("job-002".to_string()));
+        assert_eq!(node.last(), Some("job-1".to_string()));
+
+        let node = RadixTrie::with_words(["", "a"]);
+
+        assert_eq!(node.first(), Some(String::new()));
+        assert_eq!(node.last(), Some("a".to_string()));
+
+        let node = RadixTrie::new();
+
+        assert_eq!(node.first(), None);
+        assert_eq!(node.last(), None);
+    }
+
+    #[test]
+    fn test_successor() {
+
+        let node = RadixTrie::with_words(["job-002", "job-002a", "job-010", "job-1"]);
+
+        assert_eq!(node.successor("job-002"), Some("job-002a".to_string()));
+        assert_eq!(node.successor("job-002a"), Some("job-010".to_string()));
+        assert_eq!(node.successor("job-003"), Some("job-010".to_string()));
+        assert_eq!(node.successor("job-0"), Some("job-002".to_string()));
+        assert_eq!(node.successor("job-00"), Some("job-002".to_string()));
+        assert_eq!(node.successor(""), Some("job-002".to_string()));
+        assert_eq!(node.successor("job-1"), None);
+        assert_eq!(node.successor("k"), None);
+
+        assert_eq!(node.successor_or_equal("job-002"), Some("job-002".to_string()));
+        assert_eq!(node.successor_or_equal("job-003"), Some("job-010".to_string()));
+        assert_eq!(node.successor_or_equal("job-1"), Some("job-1".to_string()));
+        assert_eq!(node.successor_or_equal("job-10"), None);
+    }
+
+    #[test]
+    fn test_predecessor() {
+
+        let node = RadixTrie::with_words(["job-002", "job-002a", "job-010", "job-1"]);
+
+        assert_eq!(node.predecessor("job-010"), Some("job-002a".to_string()));
+        assert_eq!(node.predecessor("job-002a"), Some("job-002".to_string()));
+        assert_eq!(node.predecessor("job-003"), Some("job-002a".to_string()));
+        assert_eq!(node.predecessor("job-10"), Some("job-1".to_string()));
+        assert_eq!(node.predecessor("k"), Some("job-1".to_string()));
+        assert_eq!(node.predecessor("job-002"), None);
+        assert_eq!(node.predecessor("job-0"), None);
+        assert_eq!(node.predecessor(""), None);
+
+        assert_eq!(node.predecessor_or_equal("job-002"), Some("job-002".to_string()));
+        assert_eq!(node.predecessor_or_equal("job-003"), Some("job-002a".to_string()));
+        assert_eq!(node.predecessor_or_equal("job-001"), None);
+    }
+
+    #[test]
+    fn test_successor_and_predecessor_with_multibyte_keys() {
+
+        let node = RadixTrie::with_words(["日本", "日本語", "日曜"]);
+
+        /* keys are compared by their bytes, so "曜" comes before "本" */
+
+        assert_eq!(node.successor("日曜"), Some("日本".to_string()));
+        assert_eq!(node.successor("日本"), Some("日本語".to_string()));
+        assert_eq!(node.successor("日本語"), None);
+        assert_eq!(node.predecessor("日本"), Some("日曜".to_string()));
+        assert_eq!(node.predecessor("日本人"), Some("日本".to_string()));
+        assert_eq!(node.successor("日本人"), Some("日本語".to_string()));
+    }
+
+    #[test]
+    fn test_map_successor_and_predecessor_with_values() {
+
+        let map: RadixTrieMap<usize> = vec![
+            ("job-001", 1),
+            ("job-002", 2),
+            ("job-010", 10),
+        ].into_iter().collect();
+
+        assert_eq!(map.first(), Some(("job-001".to_string(), &1)));
+        assert_eq!(map.last(), Some(("job-010".to_string(), &10)));
+        assert_eq!(map.successor("job-002"), Some(("job-010".to_string(), &10)));
+        assert_eq!(map.predecessor_or_equal("job-009"), Some(("job-002".to_string(), &2)));
+    }
+
+    #[test]
+    fn test_len_is_updated_by_insertions_and_removals() {
+
+        let mut node = RadixTrie::new();
+
+        node.insert("salt");
+        node.insert("same");
+        node.insert("sa");
+        node.insert("salt");
+
+        assert_eq!(node.len(), 3);
+        assert_eq!(node.subtrie("sal").unwrap().len(), 1);
+
+        node.remove("sa");
+        node.remove("unknown");
+
+        assert_eq!(node.len(), 2);
+
+        node.insert("");
+        node.remove("salt");
+
+        assert_eq!(node.len(), 2);
+    }
+
+    #[test]
+    fn test_rank() {
+
+        let node = RadixTrie::with_words(["job-002", "job-002a", "job-010", "job-1"]);
+
+        assert_eq!(node.rank("job-002"), 0);
+        assert_eq!(node.rank("job-002a"), 1);
+        assert_eq!(node.rank("job-010"), 2);
+        assert_eq!(node.rank("job-1"), 3);
+
+        assert_eq!(node.rank(""), 0);
+        assert_eq!(node.rank("job-003"), 2);
+        assert_eq!(node.rank("job-0"), 0);
+        assert_eq!(node.rank("job-10"), 4);
+        assert_eq!(node.rank("k"), 4);
+    }
+
+    #[test]
+    fn test_select() {
+
+        let node = RadixTrie::with_words(["job-1", "job-010", "", "job-002a", "job-002"]);
+
+        assert_eq!(node.select(0), Some(String::new()));
+        assert_eq!(node.select(1), Some("job-002".to_string()));
+        assert_eq!(node.select(2), Some("job-002a".to_string()));
+        assert_eq!(node.select(3), Some("job-010".to_string()));
+        assert_eq!(node.select(4), Some("job-1".to_string()));
+        assert_eq!(node.select(5), None);
+
+        assert_eq!(RadixTrie::new().select(0), None);
+    }
+
+    #[test]
+    fn test_map_rank_and_select_for_pagination() {
+
+        let mut map = RadixTrieMap::new();
+
+        for index in 0..100 {
+            map.insert(&format!("page-{:03}", index), index);
+        }
+
+        assert_eq!(map.len(), 100);
+        assert_eq!(map.rank("page-042"), 42);
+        assert_eq!(map.select(42), Some(("page-042".to_string(), &42)));
+        assert_eq!(map.select(99), Some(("page-099".to_string(), &99)));
+        assert_eq!(map.select(100), None);
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+
+        let node = RadixTrie::with_words(["hello", "help", "hell", "yellow", "world"]);
+
+        assert_eq!(
+            node.fuzzy_search("helo", 1),
+            vec![
+                ("hell".to_string(), 1),
+                ("hello".to_string(), 1),
+                ("help".to_string(), 1),
+            ]
+        );
+
+        assert_eq!(
+            node.fuzzy_search("hello", 2),
+            vec![
+                ("hello".to_string(), 0),
+                ("hell".to_string(), 1),
+                ("help".to_string(), 2),
+                ("yellow".to_string(), 2),
+            ]
+        );
+
+        assert_eq!(node.fuzzy_search("word", 0), vec![]);
+        assert_eq!(node.fuzzy_search("word", 1), vec![("world".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_fuzzy_search_with_transpositions() {
+
+        let node = RadixTrie::with_words(["receive", "recipe"]);
+
+        assert_eq!(
+            node.fuzzy_search("recieve", 1),
+            vec![]
+        );
+
+        assert_eq!(
+            node.fuzzy_search_with("recieve", 1, Distance::Damerau),
+            vec![("receive".to_string(), 1)]
+        );
+
+        assert_eq!(
+            node.fuzzy_search_with("recieve", 2, Distance::Levenshtein),
+            vec![
+                ("receive".to_string(), 2),
+                ("recipe".to_string(), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_search_with_empty_words_and_multibyte_keys() {
+
+        let node = RadixTrie::with_words(["", "日本", "日本語", "本"]);
+
+        assert_eq!(
+            node.fuzzy_search("", 1),
+            vec![
+                (String::new(), 0),
+                ("本".to_string(), 1),
+            ]
+        );
+
+        assert_eq!(
+            node.fuzzy_search("日語", 1),
+            vec![
+                ("日本".to_string(), 1),
+                ("日本語".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_fuzzy_search_with_values() {
+
+        let map: RadixTrieMap<usize> = vec![
+            ("apple", 1),
+            ("apply", 2),
+            ("ample", 3),
+        ].into_iter().collect();
+
+        assert_eq!(
+            map.fuzzy_search("appel", 1),
+            vec![]
+        );
+
+        assert_eq!(
+            map.fuzzy_search_with("appel", 1, Distance::Damerau),
+            vec![("apple".to_string(), &1, 1)]
+        );
+
+        assert_eq!(
+            map.fuzzy_search("aple", 1),
+            vec![
+                ("ample".to_string(), &3, 1),
+                ("apple".to_string(), &1, 1),
+            ]
+        );
+    }
+
+    /// Computes the whole distance matrix between the two words.
+    fn edit_distance(first: &str, second: &str, distance: Distance) -> usize {
+
+        let first: Vec<char> = first.chars().collect();
+        let second: Vec<char> = second.chars().collect();
+
+        let mut matrix = vec![vec![0; second.len() + 1]; first.len() + 1];
+
+        for i in 0..first.len() + 1 {
+            for j in 0..second.len() + 1 {
+
+                if i == 0 || j == 0 {
+                    matrix[i][j] = i + j;
+                    continue;
+                }
+
+                let substitution = if first[i - 1] == second[j - 1] { 0 } else { 1 };
+
+                matrix[i][j] = (matrix[i - 1][j - 1] + substitution)
+                    .min(matrix[i - 1][j] + 1)
+                    .min(matrix[i][j - 1] + 1);
+
+                let transposed = distance == Distance::Damerau &&
+                    i > 1 && j > 1 &&
+                    first[i - 1] == second[j - 2] &&
+                    first[i - 2] == second[j - 1];
+
+                if transposed {
+                    matrix[i][j] = matrix[i][j].min(matrix[i - 2][j - 2] + 1);
+                }
+            }
+        }
+
+        matrix[first.len()][second.len()]
+    }
+
+    proptest! {
+
+        #[test]
+        fn test_fuzzy_search_against_edit_distance(
+            words in prop::collection::vec("[abé]{0,5}", 0..32),
+            query in "[abé]{0,5}",
+            max_distance in 0..4usize,
+            transpositions in any::<bool>(),
+        ) {
+
+            let distance = if transpositions { Distance::Damerau } else { Distance::Levenshtein };
+
+            let node: RadixTrie = words.iter().map(|word| word.as_str()).collect();
+
+            let mut expected: Vec<(String, usize)> = node.iter()
+                .map(|word| {
+                    let distance = edit_distance(&word, &query, distance);
+                    (word, distance)
+                })
+                .filter(|&(_, distance)| distance <= max_distance)
+                .collect();
+
+            expected.sort_by_key(|&(_, distance)| distance);
+
+            prop_assert_eq!(node.fuzzy_search_with(&query, max_distance, distance), expected);
+        }
+    }
+
+    #[test]
+    fn test_matches_with_single_character_wildcards() {
+
+        let node = RadixTrie::with_words([
+            "user_01/name",
+            "user_02/name",
+            "user_1/name",
+            "user_123/name",
+        ]);
+
+        assert_eq!(
+            node.matches("user_??/name"),
+            vec!["user_01/name", "user_02/name"]
+        );
+
+        assert_eq!(node.matches("user_?/name"), vec!["user_1/name"]);
+        assert_eq!(node.matches("user_01/nam"), Vec::<String>::new());
+        assert_eq!(node.matches("user_01/name"), vec!["user_01/name"]);
+    }
+
+    #[test]
+    fn test_matches_with_runs_wildcards() {
+
+        let node = RadixTrie::with_words([
+            "user_01/email",
+            "user_01/name",
+            "user_02/name",
+            "user_1/name",
+            "admin/name",
+        ]);
+
+        assert_eq!(
+            node.matches("user_??/*"),
+            vec!["user_01/email", "user_01/name", "user_02/name"]
+        );
+
+        assert_eq!(
+            node.matches("*/name"),
+            vec!["admin/name", "user_01/name", "user_02/name", "user_1/name"]
+        );
+
+        assert_eq!(node.matches("*1*e*l"), vec!["user_01/email"]);
+        assert_eq!(node.matches("*").len(), 5);
+        assert_eq!(node.matches("**?**").len(), 5);
+        assert_eq!(node.matches("?"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_matches_with_empty_words_and_multibyte_keys() {
+
+        let node = RadixTrie::with_words(["", "日本", "日本語", "本"]);
+
+        assert_eq!(node.matches(""), vec![""]);
+        assert_eq!(node.matches("?"), vec!["本"]);
+        assert_eq!(node.matches("日?"), vec!["日本"]);
+        assert_eq!(node.matches("*語"), vec!["日本語"]);
+        assert_eq!(node.matches("*"), vec!["", "日本", "日本語", "本"]);
+    }
+
+    #[test]
+    fn test_map_matches_with_values() {
+
+        let map: RadixTrieMap<usize> = vec![
+            ("cpu.core0", 10),
+            ("cpu.core1", 20),
+            ("mem.free", 30),
+        ].into_iter().collect();
+
+        assert_eq!(
+            map.matches("cpu.*"),
+            vec![
+                ("cpu.core0".to_string(), &10),
+                ("cpu.core1".to_string(), &20),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_regex() {
+
+        let node = RadixTrie::with_words([
+            "user_01",
+            "user_02",
+            "user_1a",
+            "admin",
+            "administrator",
+        ]);
+
+        let regex = Regex::new("user_[0-9]+").unwrap();
+        assert_eq!(node.search_automaton(&regex), vec!["user_01", "user_02"]);
+
+        let regex = Regex::new("admin(istrator)?|user_.a").unwrap();
+        assert_eq!(node.search_automaton(&regex), vec!["admin", "administrator", "user_1a"]);
+
+        let regex = Regex::new("[^u].*").unwrap();
+        assert_eq!(node.search_automaton(&regex), vec!["admin", "administrator"]);
+
+        let regex = Regex::new("user").unwrap();
+        assert_eq!(node.search_automaton(&regex), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_search_regex_with_escapes_and_multibyte_keys() {
+
+        let node = RadixTrie::with_words(["", "a.b", "a-b", "日本", "日本語"]);
+
+        let regex = Regex::new(r"a\.b").unwrap();
+        assert_eq!(node.search_automaton(&regex), vec!["a.b"]);
+
+        let regex = Regex::new("a[.-]b").unwrap();
+        assert_eq!(node.search_automaton(&regex), vec!["a-b", "a.b"]);
+
+        let regex = Regex::new("日.").unwrap();
+        assert_eq!(node.search_automaton(&regex), vec!["日本"]);
+
+        let regex = Regex::new("(日本語)?").unwrap();
+        assert_eq!(node.search_automaton(&regex), vec!["", "日本語"]);
+    }
+
+    #[test]
+    fn test_regex_syntax_errors() {
+
+        assert_eq!(Regex::new("(ab").unwrap_err(), RegexError::UnbalancedParenthesis(0));
+        assert_eq!(Regex::new("ab)").unwrap_err(), RegexError::UnbalancedParenthesis(2));
+        assert_eq!(Regex::new("a[bc").unwrap_err(), RegexError::UnclosedClass(1));
+        assert_eq!(Regex::new("a|*").unwrap_err(), RegexError::NothingToRepeat(2));
+        assert_eq!(Regex::new("ab\\").unwrap_err(), RegexError::TrailingEscape);
+
+        assert_eq!(
+            RegexError::NothingToRepeat(2).to_string(),
+            "nothing to repeat at position 2"
+        );
+    }
+
+    /// Automaton accepting the words of even length, and counting the read characters.
+    struct EvenLength {
+        steps: Cell<usize>,
+        max_length: usize,
+    }
+
+    impl Automaton for EvenLength {
+
+        type State = usize;
+
+        fn start(&self) -> usize {
+            0
+        }
+
+        fn step(&self, length: &usize, _: char) -> usize {
+            self.steps.set(self.steps.get() + 1);
+            length + 1
+        }
+
+        fn is_match(&self, length: &usize) -> bool {
+            length % 2 == 0
+        }
+
+        fn can_match(&self, length: &usize) -> bool {
+            *length <= self.max_length
+        }
+    }
+
+    #[test]
+    fn test_search_custom_automaton_prunes_dead_states() {
+
+        let map: RadixTrieMap<usize> = vec![
+            ("ab", 1),
+            ("abc", 2),
+            ("abcd", 3),
+            ("abcdefgh", 4),
+            ("xy", 5),
+        ].into_iter().collect();
+
+        let automaton = EvenLength {
+            steps: Cell::new(0),
+            max_length: 4,
+        };
+
+        assert_eq!(
+            map.search_automaton(&automaton),
+            vec![
+                ("ab".to_string(), &1),
+                ("abcd".to_string(), &3),
+                ("xy".to_string(), &5),
+            ]
+        );
+
+        /* "abcdefgh" is left after its fifth character */
+
+        assert_eq!(automaton.steps.get(), 7);
+    }
+
+    proptest! {
+
+        #[test]
+        fn test_matches_against_regex(
+            words in prop::collection::vec("[abé]{0,5}", 0..32),
+            pattern in "[ab?*]{0,5}",
+        ) {
+
+            let node: RadixTrie = words.iter().map(|word| word.as_str()).collect();
+
+            let expression = pattern.replace('?', ".").replace('*', ".*");
+            let regex = Regex::new(&expression).unwrap();
+
+            prop_assert_eq!(node.matches(&pattern), node.search_automaton(&regex));
+        }
+    }
+
+    #[test]
+    fn test_suffix_trie_keys_containing() {
+
+        let trie = SuffixTrie::from_iter(["banana", "bandana", "cabana", "apple"]);
+
+        assert_eq!(trie.len(), 4);
+        assert_eq!(trie.keys_containing("ana"), vec!["banana", "bandana", "cabana"]);
+        assert_eq!(trie.keys_containing("nan"), vec!["banana"]);
+        assert_eq!(trie.keys_containing("ban"), vec!["banana", "bandana", "cabana"]);
+        assert_eq!(trie.keys_containing("pl"), vec!["apple"]);
+        assert_eq!(trie.keys_containing("xyz"), Vec::<String>::new());
+        assert_eq!(trie.keys_containing("").len(), 4);
+    }
+
+    #[test]
+    fn test_suffix_trie_keys_ending_with() {
+
+        let trie = SuffixTrie::from_iter(["banana", "bandana", "cabana", "apple"]);
+
+        assert_eq!(trie.keys_ending_with("ana"), vec!["banana", "bandana", "cabana"]);
+        assert_eq!(trie.keys_ending_with("dana"), vec!["bandana"]);
+        assert_eq!(trie.keys_ending_with("apple"), vec!["apple"]);
+        assert_eq!(trie.keys_ending_with("nan"), Vec::<String>::new());
+        assert_eq!(trie.keys_ending_with("").len(), 4);
+    }
+
+    #[test]
+    fn test_suffix_trie_removal() {
+
+        let mut trie = SuffixTrie::from_iter(["banana", "cabana"]);
+
+        assert!(!trie.insert("banana"));
+        assert!(trie.remove("banana"));
+        assert!(!trie.remove("banana"));
+
+        assert!(!trie.contains_key("banana"));
+        assert_eq!(trie.keys_containing("nan"), Vec::<String>::new());
+        assert_eq!(trie.keys_containing("ana"), vec!["cabana"]);
+        assert_eq!(trie.iter().collect::<Vec<String>>(), vec!["cabana"]);
+
+        assert!(trie.remove("cabana"));
+        assert!(trie.is_empty());
+        assert_eq!(trie.keys_containing(""), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_suffix_trie_with_multibyte_keys() {
+
+        let trie = SuffixTrie::from_iter(["日本語", "英語", "日本"]);
+
+        assert_eq!(trie.keys_containing("本"), vec!["日本", "日本語"]);
+        assert_eq!(trie.keys_ending_with("語"), vec!["日本語", "英語"]);
+    }
+
+    #[test]
+    fn test_suffix_trie_is_shared_between_threads() {
+
+        fn shared<T: Send + Sync>(_: &T) {}
+
+        let trie = std::sync::Arc::new(SuffixTrie::from_iter(["banana", "cabana"]));
+        shared(&trie);
+
+        let other = trie.clone();
+        let words = std::thread::spawn(move || other.keys_containing("nan")).join().unwrap();
+
+        assert_eq!(words, vec!["banana"]);
+    }
+
+    proptest! {
+
+        #[test]
+        fn test_suffix_trie_against_words(
+            words in prop::collection::vec("[abé]{0,5}", 0..32),
+            removed in prop::collection::vec("[abé]{0,5}", 0..8),
+            fragment in "[abé]{0,3}",
+        ) {
+
+            let mut trie: SuffixTrie = words.iter().map(|word| word.as_str()).collect();
+            let mut model: BTreeSet<String> = words.iter().cloned().collect();
+
+            for word in removed.iter() {
+                prop_assert_eq!(trie.remove(word), model.remove(word));
+            }
+
+            prop_assert_eq!(
+                trie.keys_containing(&fragment),
+                model.iter().filter(|word| word.contains(fragment.as_str())).cloned().collect::<Vec<String>>()
+            );
+
+            prop_assert_eq!(
+                trie.keys_ending_with(&fragment),
+                model.iter().filter(|word| word.ends_with(fragment.as_str())).cloned().collect::<Vec<String>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_trie_map_with_integer_keys() {
+
+        let map: TrieMap<i32, &str> = vec![
+            (300, "c"),
+            (-1, "b"),
+            (7, "a"),
+            (i32::MIN, "min"),
+            (i32::MAX, "max"),
+        ].into_iter().collect();
+
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.get(&7), Some(&"a"));
+        assert_eq!(map.get(&8), None);
+
+        assert_eq!(
+            map.iter().map(|(key, _)| key).collect::<Vec<i32>>(),
+            vec![i32::MIN, -1, 7, 300, i32::MAX]
+        );
+
+        let map: TrieMap<u64, ()> = vec![(256, ()), (1, ()), (255, ())].into_iter().collect();
+
+        assert_eq!(
+            map.iter().map(|(key, _)| key).collect::<Vec<u64>>(),
+            vec![1, 255, 256]
+        );
+    }
+
+    #[test]
+    fn test_trie_map_with_byte_keys() {
+
+        let mut map = TrieMap::new();
+
+        map.insert(vec![0x00, 0xff], 1);
+        map.insert(vec![0x00, 0xff, 0x80], 2);
+        map.insert(vec![0x80], 3);
+        map.insert(Vec::new(), 4);
+
+        assert_eq!(map.insert(vec![0x80], 5), Some(3));
+        assert_eq!(map.remove(&vec![0x00]), None);
+
+        assert_eq!(
+            map.iter_prefix(&vec![0x00]).collect::<Vec<(Vec<u8>, &usize)>>(),
+            vec![(vec![0x00, 0xff], &1), (vec![0x00, 0xff, 0x80], &2)]
+        );
+
+        assert_eq!(
+            map.longest_prefix(&vec![0x00, 0xff, 0x01]),
+            Some((vec![0x00, 0xff], &1))
+        );
+
+        assert_eq!(map.iter().count(), 4);
+        assert_eq!(map.iter().next(), Some((Vec::new(), &4)));
+
+        let boxed: TrieMap<Box<[u8]>, ()> = vec![(vec![1, 2].into_boxed_slice(), ())].into_iter().collect();
+
+        assert!(boxed.contains_key(&vec![1, 2].into_boxed_slice()));
+    }
+
+    #[test]
+    fn test_trie_map_with_path_keys() {
+
+        let map: TrieMap<PathBuf, usize> = vec![
+            (PathBuf::from("/usr/lib"), 1),
+            (PathBuf::from("/usr/lib/rust"), 2),
+            (PathBuf::from("/usr/lib-extra"), 3),
+            (PathBuf::from("/usr"), 4),
+            (PathBuf::from("relative/path"), 5),
+        ].into_iter().collect();
+
+        /* paths are sorted component by component, as by the paths comparison */
+
+        let mut paths: Vec<PathBuf> = map.iter().map(|(path, _)| path).collect();
+        assert_eq!(paths[0], PathBuf::from("/usr"));
+
+        let keys = paths.clone();
+        paths.sort();
+        assert_eq!(keys, paths);
+
+        assert_eq!(
+            map.longest_prefix(&PathBuf::from("/usr/lib/rust/std")),
+            Some((PathBuf::from("/usr/lib/rust"), &2))
+        );
+
+        assert_eq!(map.get(&PathBuf::from("relative/path/")), Some(&5));
+    }
+
+    #[test]
+    fn test_trie_map_with_path_keys_matches_whole_components() {
+
+        let map: TrieMap<PathBuf, usize> = vec![
+            (PathBuf::from("/usr"), 1),
+            (PathBuf::from("/usr/lib"), 2),
+            (PathBuf::from("/usr/lib/rust"), 3),
+            (PathBuf::from("/usr/libexec"), 4),
+            (PathBuf::from("/usr/lib-extra"), 5),
+        ].into_iter().collect();
+
+        let inside: Vec<PathBuf> = map.iter_prefix(&PathBuf::from("/usr/lib")).map(|(path, _)| path).collect();
+        assert_eq!(inside, vec![PathBuf::from("/usr/lib"), PathBuf::from("/usr/lib/rust")]);
+
+        assert_eq!(
+            map.longest_prefix(&PathBuf::from("/usr/lib-extra/x")),
+            Some((PathBuf::from("/usr/lib-extra"), &5))
+        );
+
+        assert_eq!(
+            map.longest_prefix(&PathBuf::from("/usr/libexec2")),
+            Some((PathBuf::from("/usr"), &1))
+        );
+
+        assert_eq!(map.iter_prefix(&PathBuf::from("/usr/li")).count(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_trie_map_with_non_unicode_path_keys() {
+
+        use std::ffi::OsString;
+        use std::os::unix::ffi::OsStringExt;
+
+        let first = PathBuf::from(OsString::from_vec(b"/tmp/\xff".to_vec()));
+        let second = PathBuf::from(OsString::from_vec(b"/tmp/\xfe".to_vec()));
+        let escaped = PathBuf::from(OsString::from_vec(b"/tmp/a\x00\x01b".to_vec()));
+
+        let mut map = TrieMap::new();
+
+        assert_eq!(map.insert(first.clone(), 1), None);
+        assert_eq!(map.insert(second.clone(), 2), None);
+        assert_eq!(map.insert(escaped.clone(), 3), None);
+        assert_eq!(map.len(), 3);
+
+        assert_eq!(map.get(&first), Some(&1));
+        assert_eq!(map.get(&second), Some(&2));
+
+        let paths: Vec<PathBuf> = map.iter().map(|(path, _)| path).collect();
+        assert_eq!(paths, vec![escaped, second, first]);
+    }
+
+    #[test]
+    fn test_trie_map_with_address_keys() {
+
+        let map: TrieMap<IpAddr, &str> = vec![
+            ("10.0.0.2".parse().unwrap(), "b"),
+            ("::1".parse().unwrap(), "localhost"),
+            ("10.0.0.10".parse().unwrap(), "c"),
+            ("9.255.255.255".parse().unwrap(), "a"),
+        ].into_iter().collect();
+
+        assert_eq!(
+            map.iter().map(|(_, name)| *name).collect::<Vec<&str>>(),
+            vec!["a", "b", "c", "localhost"]
+        );
+
+        let address: IpAddr = "::1".parse().unwrap();
+        assert_eq!(map.get(&address), Some(&"localhost"));
+
+        let map: TrieMap<Ipv4Addr, ()> = vec![(Ipv4Addr::new(192, 168, 0, 1), ())].into_iter().collect();
+        assert_eq!(map.iter().next(), Some((Ipv4Addr::new(192, 168, 0, 1), &())));
+    }
+
+    #[test]
+    fn test_trie_map_with_string_keys() {
+
+        let map: TrieMap<String, usize> = vec![
+            ("日本".to_string(), 1),
+            ("日本語".to_string(), 2),
+        ].into_iter().collect();
+
+        assert_eq!(
+            map.iter_prefix(&"日".to_string()).collect::<Vec<(String, &usize)>>(),
+            vec![("日本".to_string(), &1), ("日本語".to_string(), &2)]
+        );
+    }
+
+    proptest! {
+
+        #[test]
+        fn test_trie_map_against_btree_map(
+            integers in prop::collection::vec(any::<i32>(), 0..32),
+            bytes in prop::collection::vec(prop::collection::vec(any::<u8>(), 0..4), 0..32),
+            prefix in prop::collection::vec(any::<u8>(), 0..2),
+        ) {
+
+            let map: TrieMap<i32, ()> = integers.iter().map(|&key| (key, ())).collect();
+            let model: BTreeMap<i32, ()> = integers.iter().map(|&key| (key, ())).collect();
+
+            prop_assert_eq!(
+                map.iter().map(|(key, _)| key).collect::<Vec<i32>>(),
+                model.keys().cloned().collect::<Vec<i32>>()
+            );
+
+            let map: TrieMap<Vec<u8>, ()> = bytes.iter().map(|key| (key.clone(), ())).collect();
+            let model: BTreeMap<Vec<u8>, ()> = bytes.iter().map(|key| (key.clone(), ())).collect();
+
+            prop_assert_eq!(
+                map.iter().map(|(key, _)| key).collect::<Vec<Vec<u8>>>(),
+                model.keys().cloned().collect::<Vec<Vec<u8>>>()
+            );
+
+            prop_assert_eq!(
+                map.iter_prefix(&prefix).map(|(key, _)| key).collect::<Vec<Vec<u8>>>(),
+                model.keys().filter(|key| key.starts_with(&prefix)).cloned().collect::<Vec<Vec<u8>>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_byte_trie_insertion_and_existence() {
+
+        let mut trie = ByteTrie::new();
+
+        assert!(trie.insert(&[0xde, 0xad, 0xbe, 0xef]));
+        assert!(trie.insert(&[0xde, 0xad, 0x00]));
+        assert!(trie.insert(&[0xff]));
+        assert!(!trie.insert(&[0xff]));
+
+        assert_eq!(trie.len(), 3);
+
+        assert!(trie.exists(&[0xde, 0xad]));
+        assert!(!trie.contains_key(&[0xde, 0xad]));
+        assert!(trie.contains_key(&[0xde, 0xad, 0x00]));
+        assert!(!trie.exists(&[0xde, 0xaf]));
+
+        let children = trie.get_children();
+
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].get_characters(), &[0xde, 0xad]);
+        assert_eq!(children[1].get_characters(), &[0xff]);
+
+        let sub_children = children[0].get_children();
+
+        assert_eq!(sub_children[0].get_characters(), &[0x00]);
+        assert_eq!(sub_children[1].get_characters(), &[0xbe, 0xef]);
+    }
+
+    #[test]
+    fn test_byte_trie_splits_between_any_bytes() {
+
+        /* the encodings of "é" and "è" share their first byte: contrary to the
+           textual tries, the byte trie splits the node in the middle of the character */
+
+        let trie: ByteTrie = vec!["é".as_bytes(), "è".as_bytes()].into_iter().collect();
+
+        let children = trie.get_children();
+
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].get_characters(), &[0xc3]);
+        assert_eq!(children[0].get_children().len(), 2);
+
+        let node = RadixTrie::with_words(["é", "è"]);
+
+        assert_eq!(node.get_children().len(), 2);
+    }
+
+    #[test]
+    fn test_byte_trie_iteration_and_removal() {
+
+        let mut trie: ByteTrie = vec![
+            &[0x01, 0x02][..],
+            &[0x01, 0x02, 0x03][..],
+            &[0x01, 0xff][..],
+            &[0x02][..],
+            &[][..],
+        ].into_iter().collect();
+
+        assert_eq!(
+            trie.iter_prefix(&[0x01]).collect::<Vec<Vec<u8>>>(),
+            vec![vec![0x01, 0x02], vec![0x01, 0x02, 0x03], vec![0x01, 0xff]]
+        );
+
+        assert_eq!(trie.iter().next(), Some(Vec::new()));
+        assert_eq!(trie.longest_prefix(&[0x01, 0x02, 0x04]), Some(&[0x01, 0x02][..]));
+
+        assert!(trie.remove(&[0x01, 0x02]));
+        assert!(!trie.remove(&[0x01]));
+
+        assert_eq!(trie.get_children()[0].get_characters(), &[0x01]);
+        assert_eq!(trie.len(), 4);
+
+        trie.clear();
+
+        assert!(trie.is_empty());
+    }
+
+    #[test]
+    fn test_byte_trie_map_with_values() {
+
+        let mut map = ByteTrieMap::default();
+
+        map.insert(&[0xca, 0xfe], "cafe");
+        map.insert(&[0xca, 0xfe, 0xba, 0xbe], "cafebabe");
+
+        assert_eq!(map.get(&[0xca, 0xfe]), Some(&"cafe"));
+        assert_eq!(map.insert(&[0xca, 0xfe], "coffee"), Some("cafe"));
+
+        assert_eq!(
+            map.iter().collect::<Vec<(Vec<u8>, &&str)>>(),
+            vec![
+                (vec![0xca, 0xfe], &"coffee"),
+                (vec![0xca, 0xfe, 0xba, 0xbe], &"cafebabe"),
+            ]
+        );
+    }
+
+    /// Checks the byte trie children are sorted by their first byte,
+    /// and that every node without value has two children at least.
+    ///
+    /// # Args:
+    ///
+    /// `children` - the children to check
+    fn check_byte_children(children: &[ByteTrieMap<()>]) {
+
+        let first_bytes: Vec<u8> = children
+            .iter()
+            .map(|child| child.get_characters()[0])
+            .collect();
+
+        assert!(first_bytes.windows(2).all(|pair| pair[0] < pair[1]));
+
+        for child in children {
+
+            if !child.is_terminal() {
+                assert!(child.get_children().len() >= 2);
+            }
+
+            check_byte_children(child.get_children());
+        }
+    }
+
+    proptest! {
+
+        #[test]
+        fn test_byte_trie_against_btree_set(
+            operations in prop::collection::vec((any::<bool>(), prop::collection::vec(0..4u8, 0..4)), 0..64),
+            prefix in prop::collection::vec(0..4u8, 0..3),
+        ) {
+
+            let mut trie = ByteTrie::new();
+            let mut model = BTreeSet::new();
+
+            for &(insertion, ref key) in operations.iter() {
+
+                if insertion {
+                    prop_assert_eq!(trie.insert(key), model.insert(key.clone()));
+                } else {
+                    prop_assert_eq!(trie.remove(key), model.remove(key));
+                }
+
+                check_byte_children(trie.get_children());
+            }
+
+            prop_assert_eq!(trie.len(), model.len());
+
+            prop_assert_eq!(
+                trie.iter().collect::<Vec<Vec<u8>>>(),
+                model.iter().cloned().collect::<Vec<Vec<u8>>>()
+            );
+
+            prop_assert_eq!(
+                trie.iter_prefix(&prefix).collect::<Vec<Vec<u8>>>(),
+                model.iter().filter(|key| key.starts_with(&prefix)).cloned().collect::<Vec<Vec<u8>>>()
+            );
+
+            prop_assert_eq!(trie.exists(&prefix), model.iter().any(|key| key.starts_with(&prefix)));
+        }
+    }
+
+    /// Creates a bit vector from booleans.
+    ///
+    /// # Args:
+    ///
+    /// `bits` - the bits to pack
+    ///
+    /// # Returns:
+    ///
+    /// the packed bits
+    fn to_bits(bits: &[bool]) -> Bits {
+
+        let mut packed = Bits::new();
+
+        for &bit in bits {
+            packed.push(bit);
+        }
+
+        packed
+    }
+
+    #[test]
+    fn test_bit_trie_map_packs_labels_and_splits_between_bits() {
+
+        let mut map = BitTrieMap::default();
+        let address = [0xc0, 0xa8, 0x00, 0x01];
+
+        map.insert(BitSlice::new(&address, 32), 1);
+
+        assert_eq!(map.get_children()[0].get_characters().len(), 32);
+        assert_eq!(map.keys().next().unwrap().as_bytes(), &address[..]);
+
+        /* 0xc0a8 and 0xc0b0 share their 11 first bits */
+
+        map.insert(BitSlice::new(&[0xc0, 0xb0], 16), 2);
+
+        let child = &map.get_children()[0];
+
+        assert_eq!(format!("{:?}", child.get_characters()), "11000000101");
+        assert_eq!(child.get_children().len(), 2);
+
+        assert_eq!(map.get(BitSlice::new(&[0xc0, 0xb0], 16)), Some(&2));
+        assert_eq!(map.get(BitSlice::new(&[0xc0, 0xa0], 12)), None);
+        assert!(map.has_prefix(BitSlice::new(&[0xc0, 0xa0], 12)));
+
+        assert_eq!(
+            map.longest_prefix(BitSlice::new(&[0xc0, 0xb0, 0xff], 24)).map(|(bits, value)| (bits.len(), *value)),
+            Some((16, 2))
+        );
+
+        assert_eq!(to_bits(&[true, false, true]).as_bytes(), &[0xa0]);
+    }
+
+    proptest! {
+
+        #[test]
+        fn test_bit_trie_map_against_btree_map(
+            keys in prop::collection::vec(prop::collection::vec(any::<bool>(), 0..20), 0..32),
+            removed in prop::collection::vec(prop::collection::vec(any::<bool>(), 0..20), 0..8),
+            query in prop::collection::vec(any::<bool>(), 0..20),
+        ) {
+
+            let mut map = BitTrieMap::default();
+            let mut model = BTreeMap::new();
+
+            for (value, key) in keys.iter().enumerate() {
+                let bits = to_bits(key);
+                prop_assert_eq!(map.insert(bits.as_bit_slice(), value), model.insert(key.clone(), value));
+            }
+
+            for key in removed.iter() {
+                let bits = to_bits(key);
+                prop_assert_eq!(map.remove(bits.as_bit_slice()), model.remove(key));
+            }
+
+            let entries: Vec<(Vec<bool>, usize)> = map.iter()
+                .map(|(bits, value)| (bits.as_bit_slice().iter().collect(), *value))
+                .collect();
+
+            prop_assert_eq!(entries, model.iter().map(|(key, value)| (key.clone(), *value)).collect::<Vec<(Vec<bool>, usize)>>());
+
+            let bits = to_bits(&query);
+            let expected = model.iter()
+                .filter(|&(key, _)| query.starts_with(key))
+                .max_by_key(|&(key, _)| key.len())
+                .map(|(key, value)| (key.len(), value));
+
+            prop_assert_eq!(map.longest_prefix(bits.as_bit_slice()).map(|(key, value)| (key.len(), value)), expected);
+        }
+    }
+
+    #[test]
+    fn test_ip_prefix_parsing() {
+
+        let prefix: IpPrefix = "10.1.2.3/8".parse().unwrap();
+
+        assert_eq!(prefix.address(), IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)));
+        assert_eq!(prefix.length(), 8);
+        assert_eq!(prefix.to_string(), "10.0.0.0/8");
+
+        assert_eq!("2001:db8::1/32".parse::<IpPrefix>().unwrap().to_string(), "2001:db8::/32");
+        assert_eq!("192.168.0.1".parse::<IpPrefix>().unwrap().length(), 32);
+        assert_eq!("::1".parse::<IpPrefix>().unwrap().length(), 128);
+
+        assert_eq!("10.0.0.0/33".parse::<IpPrefix>(), Err(PrefixError::InvalidLength));
+        assert_eq!("10.0.0.0/x".parse::<IpPrefix>(), Err(PrefixError::InvalidLength));
+        assert_eq!("10.0.0/8".parse::<IpPrefix>(), Err(PrefixError::InvalidAddress));
+
+        assert!(prefix.contains("10.200.0.1".parse().unwrap()));
+        assert!(!prefix.contains("11.0.0.1".parse().unwrap()));
+        assert!(!prefix.contains("a00::".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_ip_prefix_table_longest_match() {
+
+        let table: IpPrefixTable<&str> = vec![
+            ("0.0.0.0/0".parse().unwrap(), "default"),
+            ("10.0.0.0/8".parse().unwrap(), "private"),
+            ("10.128.0.0/9".parse().unwrap(), "upper half"),
+            ("10.128.0.1/32".parse().unwrap(), "host"),
+            ("2001:db8::/32".parse().unwrap(), "documentation"),
+        ].into_iter().collect();
+
+        let route = |address: &str| table
+            .longest_match(address.parse().unwrap())
+            .map(|(prefix, value)| (prefix.to_string(), *value));
+
+        assert_eq!(route("10.1.2.3"), Some(("10.0.0.0/8".to_string(), "private")));
+        assert_eq!(route("10.129.0.1"), Some(("10.128.0.0/9".to_string(), "upper half")));
+        assert_eq!(route("10.128.0.1"), Some(("10.128.0.1/32".to_string(), "host")));
+        assert_eq!(route("8.8.8.8"), Some(("0.0.0.0/0".to_string(), "default")));
+        assert_eq!(route("2001:db8:1::1"), Some(("2001:db8::/32".to_string(), "documentation")));
+        assert_eq!(route("2001:db9::1"), None);
+    }
+
+    #[test]
+    fn test_ip_prefix_table_bit_granularity() {
+
+        let mut table = IpPrefixTable::new();
+
+        /* both prefixes share 17 bits: the node is split inside the third octet */
+
+        table.insert("192.168.0.0/24".parse().unwrap(), 1);
+        table.insert("192.168.64.0/18".parse().unwrap(), 2);
+
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.get(&"192.168.0.0/24".parse().unwrap()), Some(&1));
+        assert_eq!(table.get(&"192.168.0.0/17".parse().unwrap()), None);
+        assert_eq!(table.longest_match("192.168.1.1".parse().unwrap()), None);
+        assert_eq!(table.longest_match("192.168.100.1".parse().unwrap()).map(|(_, value)| *value), Some(2));
+
+        *table.get_mut(&"192.168.64.0/18".parse().unwrap()).unwrap() = 3;
+
+        assert_eq!(table.remove(&"192.168.0.0/24".parse().unwrap()), Some(1));
+        assert!(!table.contains_key(&"192.168.0.0/24".parse().unwrap()));
+        assert_eq!(
+            table.iter().map(|(prefix, value)| (prefix.to_string(), *value)).collect::<Vec<(String, i32)>>(),
+            vec![("192.168.64.0/18".to_string(), 3)]
+        );
+
+        table.clear();
+
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn test_ip_prefix_table_separates_versions() {
+
+        let mut table = IpPrefixTable::new();
+
+        table.insert("0.0.0.0/0".parse().unwrap(), "v4");
+        table.insert("::/0".parse().unwrap(), "v6");
+        table.insert("::/96".parse().unwrap(), "compatible");
+
+        assert_eq!(table.longest_match("1.2.3.4".parse().unwrap()).map(|(_, value)| *value), Some("v4"));
+        assert_eq!(table.longest_match("::1.2.3.4".parse().unwrap()).map(|(_, value)| *value), Some("compatible"));
+        assert_eq!(table.longest_match("ff02::1".parse().unwrap()).map(|(_, value)| *value), Some("v6"));
+
+        assert_eq!(
+            table.iter().map(|(prefix, _)| prefix.to_string()).collect::<Vec<String>>(),
+            vec!["0.0.0.0/0", "::/0", "::/96"]
+        );
+    }
+
+    proptest! {
+
+        #[test]
+        fn test_ip_prefix_table_against_linear_scan(
+            prefixes in prop::collection::vec((any::<u32>(), 0..=32u8), 0..32),
+            addresses in prop::collection::vec(any::<u32>(), 0..16),
+        ) {
+
+            let mut table = IpPrefixTable::new();
+            let mut model = BTreeMap::new();
+
+            for &(address, length) in prefixes.iter() {
+
+                let prefix = IpPrefix::new(IpAddr::V4(Ipv4Addr::from(address)), length).unwrap();
+
+                prop_assert_eq!(table.insert(prefix, length), model.insert(prefix, length));
+            }
+
+            prop_assert_eq!(table.len(), model.len());
+
+            for &address in addresses.iter().chain(prefixes.iter().map(|&(address, _)| address).collect::<Vec<u32>>().iter()) {
+
+                let address = IpAddr::V4(Ipv4Addr::from(address));
+
+                let expected = model
+                    .iter()
+                    .filter(|&(prefix, _)| prefix.contains(address))
+                    .max_by_key(|&(prefix, _)| prefix.length())
+                    .map(|(prefix, value)| (*prefix, value));
+
+                prop_assert_eq!(table.longest_match(address), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_router_resolves_parameters() {
+
+        let mut router = Router::new();
+
+        router.insert("/", "index").unwrap();
+        router.insert("/users", "users").unwrap();
+        router.insert("/users/:id", "user").unwrap();
+        router.insert("/users/:id/posts/*rest", "posts").unwrap();
+
+        assert_eq!(router.len(), 4);
+
+        assert_eq!(router.resolve("/").map(|found| *found.handler()), Some("index"));
+        assert_eq!(router.resolve("/users").map(|found| *found.handler()), Some("users"));
+
+        let found = router.resolve("/users/42").unwrap();
+
+        assert_eq!(*found.handler(), "user");
+        assert_eq!(found.pattern(), "/users/:id");
+        assert_eq!(found.param("id"), Some("42"));
+
+        let found = router.resolve("/users/42/posts/2024/01").unwrap();
+
+        assert_eq!(*found.handler(), "posts");
+        assert_eq!(found.params(), &[("id", "42"), ("rest", "2024/01")]);
+
+        assert!(router.resolve("/users/").is_none());
+        assert!(router.resolve("/users/42/comments").is_none());
+        assert!(router.resolve("/unknown").is_none());
+    }
+
+    #[test]
+    fn test_router_static_priority() {
+
+        let mut router = Router::new();
+
+        router.insert("/users/new", 1).unwrap();
+        router.insert("/users/:id", 2).unwrap();
+        router.insert("/users/:id/edit", 3).unwrap();
+        router.insert("/users/new/edit", 4).unwrap();
+        router.insert("/files/*path", 5).unwrap();
+        router.insert("/files/readme", 6).unwrap();
+
+        assert_eq!(router.resolve("/users/new").map(|found| *found.handler()), Some(1));
+        assert_eq!(router.resolve("/users/newer").map(|found| *found.handler()), Some(2));
+        assert_eq!(router.resolve("/users/new/edit").map(|found| *found.handler()), Some(4));
+        assert_eq!(router.resolve("/users/7/edit").map(|found| *found.handler()), Some(3));
+
+        /* the static route does not match, so the router goes back to the catch-all */
+
+        assert_eq!(router.resolve("/files/readme").map(|found| *found.handler()), Some(6));
+        assert_eq!(router.resolve("/files/readme/old").unwrap().param("path"), Some("readme/old"));
+        assert_eq!(router.resolve("/files/").unwrap().param("path"), Some(""));
+    }
+
+    #[test]
+    fn test_router_reports_conflicts() {
+
+        let mut router = Router::new();
+
+        router.insert("/users/:id", ()).unwrap();
+        router.insert("/static/*file", ()).unwrap();
+
+        assert_eq!(
+            router.insert("/users/:name/posts", ()),
+            Err(RouteError::ConflictingRoute("/users/:name/posts".to_string(), "/users/:id".to_string()))
+        );
+        assert_eq!(
+            router.insert("/users/*rest", ()),
+            Err(RouteError::ConflictingRoute("/users/*rest".to_string(), "/users/:id".to_string()))
+        );
+        assert_eq!(
+            router.insert("/static/:name", ()),
+            Err(RouteError::ConflictingRoute("/static/:name".to_string(), "/static/*file".to_string()))
+        );
+        assert_eq!(
+            router.insert("/static/*path", ()),
+            Err(RouteError::ConflictingRoute("/static/*path".to_string(), "/static/*file".to_string()))
+        );
+        assert_eq!(router.insert("/users/:id", ()), Err(RouteError::DuplicateRoute("/users/:id".to_string())));
+        assert_eq!(router.insert("/static/*file", ()), Err(RouteError::DuplicateRoute("/static/*file".to_string())));
+
+        /* the failed insertions did not create any node */
+
+        assert_eq!(router.len(), 2);
+        assert!(router.resolve("/users/1/posts").is_none());
+
+        assert!(router.insert("/users/:id/posts", ()).is_ok());
+    }
+
+    #[test]
+    fn test_router_invalid_patterns() {
+
+        let mut router = Router::new();
+
+        for pattern in ["users", "/users/:", "/files/*", "/files/*path/more", "/:id/:id", "/a:b:c"] {
+            assert_eq!(router.insert(pattern, ()), Err(RouteError::InvalidPattern(pattern.to_string())));
+        }
+
+        assert!(router.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_flat_forms() {
+
+        let trie = RadixTrie::with_words(["same", "salt", "sa"]);
+        let json = serde_json::to_string(&trie).unwrap();
+
+        assert_eq!(json, r#"["sa","salt","same"]"#);
+
+        let trie: RadixTrie = serde_json::from_str(r#"["salt","same","salt",""]"#).unwrap();
+
+        assert_eq!(trie.len(), 3);
+        assert!(trie.contains_key(""));
+        assert_eq!(trie.get_children()[0].get_characters(), "sa");
+
+        let map: RadixTrieMap<u32> = vec![("one", 1), ("only", 2)].into_iter().collect();
+        let json = serde_json::to_string(&map).unwrap();
+
+        assert_eq!(json, r#"{"one":1,"only":2}"#);
+
+        let map: RadixTrieMap<u32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(map.get("only"), Some(&2));
+        assert!(serde_json::from_str::<RadixTrieMap<u32>>(r#"{"one":"1"}"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_node_tree_forms() {
+
+        let trie = RadixTrie::with_words(["salt", "same"]);
+
+        assert_eq!(
+            serde_json::to_string(&NodeTree(&trie)).unwrap(),
+            concat!(
+                r#"{"characters":"","terminal":false,"children":[{"characters":"sa","terminal":false,"children":["#,
+                r#"{"characters":"lt","terminal":true,"children":[]},"#,
+                r#"{"characters":"me","terminal":true,"children":[]}]}]}"#,
+            )
+        );
+
+        let map: RadixTrieMap<u32> = vec![("", 0), ("ab", 1)].into_iter().collect();
+        let json = serde_json::to_string(&NodeTree(&map)).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"characters":"","value":0,"children":[{"characters":"ab","value":1,"children":[]}]}"#
+        );
+
+        let NodeTree(copy) = serde_json::from_str::<NodeTree<RadixTrieMap<u32>>>(&json).unwrap();
+
+        assert_eq!(copy.len(), 2);
+        assert_eq!(copy.get(""), Some(&0));
+        assert_eq!(copy.get("ab"), Some(&1));
+
+        /* missing terminal flags and children are allowed */
+
+        let NodeTree(trie) = serde_json::from_str::<NodeTree<RadixTrie>>(
+            r#"{"characters":"","children":[{"characters":"a","terminal":true}]}"#
+        ).unwrap();
+
+        assert_eq!(trie.iter().collect::<Vec<String>>(), vec!["a"]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_node_tree_invariants() {
+
+        let invalid = [
+            r#"{"characters":"a","children":[]}"#,
+            r#"{"characters":"","children":[{"characters":"","terminal":true}]}"#,
+            r#"{"characters":"","children":[{"characters":"a","children":[{"characters":"b","terminal":true}]}]}"#,
+            r#"{"characters":"","children":[{"characters":"a"}]}"#,
+            r#"{"characters":"","children":[{"characters":"b","terminal":true},{"characters":"a","terminal":true}]}"#,
+            r#"{"characters":"","children":[{"characters":"ab","terminal":true},{"characters":"ac","terminal":true}]}"#,
+        ];
+
+        for json in invalid.iter() {
+            assert!(serde_json::from_str::<NodeTree<RadixTrie>>(json).is_err(), "{}", json);
+        }
+
+        let error = serde_json::from_str::<NodeTree<RadixTrieMap<u32>>>(
+            r#"{"characters":"","value":null,"children":[{"characters":"x","value":null,"children":[]}]}"#
+        ).err().unwrap();
+
+        assert!(error.to_string().contains("node \"x\" has no value and less than two children"));
+    }
+
+    #[cfg(feature = "serde")]
+    proptest! {
+
+        #[test]
+        fn test_serde_round_trips(words in prop::collection::btree_set("[a-c]{0,4}", 0..24)) {
+
+            let trie = RadixTrie::with_words(words.iter().map(|word| &word[..]));
+
+            let flat: RadixTrie = serde_json::from_str(&serde_json::to_string(&trie).unwrap()).unwrap();
+            let NodeTree(tree) = serde_json::from_str::<NodeTree<RadixTrie>>(
+                &serde_json::to_string(&NodeTree(&trie)).unwrap()
+            ).unwrap();
+
+            for copy in [flat, tree].iter() {
+
+                prop_assert_eq!(copy.len(), words.len());
+                prop_assert_eq!(copy.iter().collect::<Vec<String>>(), words.iter().cloned().collect::<Vec<String>>());
+
+                check_children(copy.get_children());
+            }
+        }
+    }
+
+    #[test]
+    fn test_frozen_trie_queries() {
+
+        let trie = RadixTrie::with_words(["salt", "same", "sam", "日本", "日曜", "x"]);
+        let bytes = trie.to_bytes().unwrap();
+        let frozen = FrozenRadixTrie::from_bytes(&bytes).unwrap();
+
+        assert_eq!(frozen.len(), 6);
+
+        assert!(frozen.exists("sa"));
+        assert!(frozen.exists("日"));
+        assert!(!frozen.exists("sale"));
+
+        assert!(frozen.contains_key("sam"));
+        assert!(frozen.contains_key("日本"));
+        assert!(!frozen.contains_key("sa"));
+        assert!(!frozen.contains_key("samey"));
+
+        assert_eq!(frozen.longest_prefix("samely"), Some("same"));
+        assert_eq!(frozen.longest_prefix("samuel"), Some("sam"));
+        assert_eq!(frozen.longest_prefix("sa"), None);
+
+        assert_eq!(frozen.iter_prefix("sa").collect::<Vec<String>>(), vec!["salt", "sam", "same"]);
+        assert_eq!(frozen.iter_prefix("日").collect::<Vec<String>>(), vec!["日曜", "日本"]);
+        assert_eq!(frozen.iter_prefix("z").count(), 0);
+        assert_eq!(frozen.iter().collect::<Vec<String>>(), trie.iter().collect::<Vec<String>>());
+    }
+
+    #[test]
+    fn test_frozen_trie_empty_and_empty_word() {
+
+        let bytes = RadixTrie::new().to_bytes().unwrap();
+        let frozen = FrozenRadixTrie::from_bytes(&bytes).unwrap();
+
+        assert!(frozen.is_empty());
+        assert!(!frozen.exists(""));
+        assert_eq!(frozen.iter().count(), 0);
+
+        let bytes = RadixTrie::with_words(["", "a"]).to_bytes().unwrap();
+        let frozen = FrozenRadixTrie::from_bytes(&bytes).unwrap();
+
+        assert!(frozen.contains_key(""));
+        assert_eq!(frozen.longest_prefix("b"), Some(""));
+        assert_eq!(frozen.iter().collect::<Vec<String>>(), vec!["", "a"]);
+    }
+
+    #[test]
+    fn test_frozen_trie_rejects_invalid_bytes() {
+
+        let bytes = RadixTrie::with_words(["salt", "same"]).to_bytes().unwrap();
+
+        assert!(FrozenRadixTrie::from_bytes(&bytes).is_ok());
+
+        assert_eq!(FrozenRadixTrie::from_bytes(b"").err(), Some(FormatError::InvalidMagic));
+        assert_eq!(FrozenRadixTrie::from_bytes(&bytes[..10]).err(), Some(FormatError::Truncated));
+
+        let mut modified = bytes.clone();
+        modified[0] = b'X';
+
+        assert_eq!(FrozenRadixTrie::from_bytes(&modified).err(), Some(FormatError::InvalidMagic));
+
+        let mut modified = bytes.clone();
+        modified[4] = 2;
+
+        assert_eq!(FrozenRadixTrie::from_bytes(&modified).err(), Some(FormatError::UnsupportedVersion(2)));
+
+        /* every modification of the words is detected */
+
+        for index in 20..bytes.len() {
+
+            let mut modified = bytes.clone();
+            modified[index] ^= 0x01;
+
+            assert_eq!(FrozenRadixTrie::from_bytes(&modified).err(), Some(FormatError::ChecksumMismatch));
+        }
+
+        assert_eq!(
+            FrozenRadixTrie::from_bytes(&bytes[..bytes.len() - 1]).err(),
+            Some(FormatError::ChecksumMismatch)
+        );
+    }
+
+    #[test]
+    fn test_frozen_trie_rejects_crafted_records() {
+
+        /* records and headers are written by hand, with a valid checksum */
+
+        let record = |terminal: bool, characters: &[u8], children: &[u32]| {
+
+            let mut bytes = vec![terminal as u8];
+            bytes.extend_from_slice(&(characters.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(characters);
+            bytes.extend_from_slice(&(children.len() as u32).to_le_bytes());
+
+            for child in children {
+                bytes.extend_from_slice(&child.to_le_bytes());
+            }
+
+            bytes
+        };
+
+        let seal = |count: u32, root: u32, records: &[Vec<u8>]| {
+
+            let mut bytes = b"RDXT".to_vec();
+            bytes.extend_from_slice(&1u32.to_le_bytes());
+            bytes.extend_from_slice(&count.to_le_bytes());
+            bytes.extend_from_slice(&root.to_le_bytes());
+
+            let body = records.concat();
+            let sum = bytes.iter()
+                .chain(body.iter())
+                .fold(0x811c_9dc5u32, |sum, &byte| (sum ^ byte as u32).wrapping_mul(0x0100_0193));
+
+            bytes.extend_from_slice(&sum.to_le_bytes());
+            bytes.extend_from_slice(&body);
+            bytes
+        };
+
+        let a = record(true, b"a", &[]);
+        let b = record(true, b"b", &[]);
+
+        let bytes = seal(2, 20, &[a.clone(), b.clone(), record(false, b"", &[0, 10])]);
+        let trie = FrozenRadixTrie::from_bytes(&bytes).unwrap();
+
+        assert_eq!(trie.iter().collect::<Vec<String>>(), vec!["a", "b"]);
+
+        /* the characters of the first record read as a record whose child is itself,
+           and the root points into them instead of pointing to the start of a record */
+
+        let looping = record(false, b"", &[5]);
+        let bytes = seal(0, 22, &[record(false, &looping, &[]), record(false, b"", &[5])]);
+
+        assert_eq!(FrozenRadixTrie::from_bytes(&bytes).err(), Some(FormatError::InvalidNode(22)));
+
+        /* the children are binary searched by their first character */
+
+        let bytes = seal(2, 20, &[b.clone(), a.clone(), record(false, b"", &[0, 10])]);
+        assert_eq!(FrozenRadixTrie::from_bytes(&bytes).err(), Some(FormatError::InvalidNode(20)));
+
+        let bytes = seal(2, 22, &[record(true, b"ab", &[]), record(true, b"ac", &[]), record(false, b"", &[0, 11])]);
+        assert_eq!(FrozenRadixTrie::from_bytes(&bytes).err(), Some(FormatError::InvalidNode(22)));
+
+        /* every record but the root has a single parent */
+
+        let bytes = seal(1, 10, &[a.clone(), record(false, b"", &[0, 0])]);
+        assert_eq!(FrozenRadixTrie::from_bytes(&bytes).err(), Some(FormatError::InvalidNode(10)));
+
+        let bytes = seal(2, 20, &[a.clone(), b.clone(), record(false, b"", &[10])]);
+        assert_eq!(FrozenRadixTrie::from_bytes(&bytes).err(), Some(FormatError::InvalidNode(0)));
+
+        /* only the root has no characters */
+
+        let bytes = seal(0, 9, &[record(false, b"", &[]), record(false, b"", &[0])]);
+        assert_eq!(FrozenRadixTrie::from_bytes(&bytes).err(), Some(FormatError::InvalidNode(9)));
+
+        /* the amount of words of the header is the amount of terminal records */
+
+        let bytes = seal(3, 20, &[a, b, record(false, b"", &[0, 10])]);
+        assert_eq!(FrozenRadixTrie::from_bytes(&bytes).err(), Some(FormatError::CountMismatch));
+    }
+
+    #[test]
+    fn test_frozen_integers_too_large_for_the_format() {
+
+        let mut bytes = Vec::new();
+
+        assert_eq!(write_u32(&mut bytes, u32::MAX as usize), Ok(()));
+        assert_eq!(bytes, vec![0xff; 4]);
+
+        if let Some(integer) = (u32::MAX as usize).checked_add(1) {
+            assert_eq!(write_u32(&mut bytes, integer), Err(FormatError::TooLarge));
+            assert_eq!(bytes.len(), 4);
+        }
+    }
+
+    proptest! {
+
+        #[test]
+        fn test_frozen_trie_against_radix_trie(
+            words in prop::collection::btree_set("[ab日]{0,5}", 0..32),
+            queries in prop::collection::vec("[ab日]{0,6}", 0..16),
+        ) {
+
+            let trie = RadixTrie::with_words(words.iter().map(|word| &word[..]));
+            let bytes = trie.to_bytes().unwrap();
+            let frozen = FrozenRadixTrie::from_bytes(&bytes).unwrap();
+
+            prop_assert_eq!(frozen.len(), trie.len());
+            prop_assert_eq!(frozen.iter().collect::<Vec<String>>(), trie.iter().collect::<Vec<String>>());
+
+            for query in queries.iter() {
+
+                prop_assert_eq!(frozen.exists(query), trie.exists(query));
+                prop_assert_eq!(frozen.contains_key(query), trie.contains_key(query));
+                prop_assert_eq!(frozen.longest_prefix(query), trie.longest_prefix(query));
+                prop_assert_eq!(
+                    frozen.iter_prefix(query).collect::<Vec<String>>(),
+                    trie.iter_prefix(query).collect::<Vec<String>>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_sorted_iter_builds_compressed_nodes() {
+
+        let words = ["", "ab", "abc", "abd", "b", "ba", "日曜", "日本"];
+        let trie = RadixTrie::from_sorted_iter(words.iter().cloned()).unwrap();
+
+        assert_eq!(trie.len(), words.len());
+        assert!(trie.contains_key(""));
+        assert_eq!(trie.iter().collect::<Vec<String>>(), words.to_vec());
+
+        let children = trie.get_children();
+
+        assert_eq!(children.len(), 3);
+        assert_eq!(children[0].get_characters(), "ab");
+        assert_eq!(children[0].get_children().len(), 2);
+        assert_eq!(children[2].get_characters(), "日");
+
+        check_children(children);
+    }
+
+    #[test]
+    fn test_from_sorted_iter_splits_previous_node() {
+
+        /* "abc" is stored as a single node until "abd" shares only "ab" with it */
+
+        let trie = RadixTrie::from_sorted_iter(vec!["abc", "abd", "abde"]).unwrap();
+        let children = trie.get_children();
+
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].get_characters(), "ab");
+        assert!(!children[0].is_terminal());
+        assert_eq!(children[0].get_children()[1].get_children()[0].get_characters(), "e");
+
+        assert_eq!(trie.rank("abd"), 1);
+        assert_eq!(trie.select(2), Some("abde".to_string()));
+    }
+
+    #[test]
+    fn test_from_sorted_iter_rejects_unsorted_keys() {
+
+        assert_eq!(
+            RadixTrie::from_sorted_iter(vec!["a", "c", "b"]).err(),
+            Some(BuildError::UnsortedKey("b".to_string()))
+        );
+        assert_eq!(
+            RadixTrie::from_sorted_iter(vec!["a", "a"]).err(),
+            Some(BuildError::DuplicateKey("a".to_string()))
+        );
+
+        let mut builder = TrieBuilder::new();
+
+        builder.push("one", 1).unwrap();
+        builder.push("two", 2).unwrap();
+
+        assert!(builder.push("three", 3).is_err());
+
+        builder.push("zero", 0).unwrap();
+
+        let map = builder.build();
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get("zero"), Some(&0));
+        assert_eq!(map.get("three"), None);
+    }
+
+    #[test]
+    fn test_from_sorted_iter_map_values() {
+
+        let map = RadixTrieMap::from_sorted_iter(vec![("salt", 1), ("same", 2), ("sea", 3)]).unwrap();
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get("same"), Some(&2));
+        assert_eq!(map.longest_prefix("seaside"), Some(("sea", &3)));
+
+        assert!(TrieBuilder::<u32>::new().build().is_empty());
+    }
+
+    proptest! {
+
+        #[test]
+        fn test_from_sorted_iter_against_insertions(words in prop::collection::btree_set("[ab日]{0,6}", 0..48)) {
+
+            let built = RadixTrie::from_sorted_iter(words.iter().map(|word| &word[..])).unwrap();
+            let inserted = RadixTrie::with_words(words.iter().map(|word| &word[..]));
+
+            prop_assert_eq!(built.len(), words.len());
+            prop_assert_eq!(built.iter().collect::<Vec<String>>(), words.iter().cloned().collect::<Vec<String>>());
+
+            /* the same keys always give the same nodes */
+
+            prop_assert_eq!(built.to_bytes().unwrap(), inserted.to_bytes().unwrap());
+
+            check_children(built.get_children());
+        }
+    }
+
+    #[test]
+    fn test_split_moves_subtrees() {
+
+        /* the values are not clonable: the subtrees can only be moved */
+
+        struct Unique(usize);
+
+        let mut map = RadixTrieMap::new();
+
+        map.insert("abcd", Unique(1));
+        map.insert("abce", Unique(2));
+
+        let address = map.get("abcd").unwrap() as *const Unique;
+
+        map.insert("ab", Unique(3));
+        map.insert("a", Unique(4));
+        map.insert("b", Unique(5));
+
+        assert!(std::ptr::eq(map.get("abcd").unwrap(), address));
+
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.get("abce").map(|value| value.0), Some(2));
+        assert_eq!(
+            map.values().map(|value| value.0).collect::<Vec<usize>>(),
+            vec![4, 3, 1, 2, 5]
+        );
+
+        let children = map.get_children();
+
+        assert_eq!(children[0].get_characters(), "a");
+        assert_eq!(children[0].get_children()[0].get_characters(), "b");
+        assert_eq!(children[0].get_children()[0].get_children()[0].get_characters(), "c");
+        assert_eq!(children[0].get_children()[0].get_children()[0].len(), 2);
+    }
 }